@@ -0,0 +1,133 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use utils::solution::Part;
+
+/// The conventional name of the answers file, stored alongside the inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known correct answers, keyed by day and part.
+///
+/// Answers are stored as a small subset of TOML, with one table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = 142
+/// part2 = "281"
+/// ```
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: HashMap<(u8, Part), String>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<AnswerStore, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read answers file '{}': {e}", path.display()))?;
+
+        AnswerStore::parse(&contents).map_err(|e| format!("{}:{e}", path.display()))
+    }
+
+    pub fn parse(s: &str) -> Result<AnswerStore, String> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for (idx, line) in s.lines().enumerate() {
+            let line_num = idx + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|t| t.trim().strip_prefix("day"))
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .ok_or(format!("{line_num}: expected a table like '[day01]'"))?;
+
+                day = Some(number);
+                continue;
+            }
+
+            let day = day.ok_or(format!("{line_num}: answer given outside of a day table"))?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("{line_num}: expected 'part1 = <answer>'"))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(format!("{line_num}: unexpected key '{key}'")),
+            };
+
+            let value = parse_value(value.trim()).ok_or(format!(
+                "{line_num}: expected a quoted string or an integer"
+            ))?;
+
+            answers.insert((day, part), value);
+        }
+
+        Ok(AnswerStore { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| s.as_str())
+    }
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return quoted.strip_suffix('"').map(|s| s.to_string());
+    }
+
+    value.parse::<i128>().ok().map(|n| n.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::solution::Part;
+
+    use super::AnswerStore;
+
+    #[test]
+    pub fn parse() {
+        let store = AnswerStore::parse(
+            r#"
+            # Comments and blank lines are ignored
+
+            [day01]
+            part1 = 142
+            part2 = "281"
+
+            [day12]
+            part2 = -5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("142"), store.get(1, Part::One));
+        assert_eq!(Some("281"), store.get(1, Part::Two));
+        assert_eq!(None, store.get(12, Part::One));
+        assert_eq!(Some("-5"), store.get(12, Part::Two));
+    }
+
+    #[test]
+    pub fn parse_errors() {
+        let cases = [
+            "part1 = 1",
+            "[dayone]\npart1 = 1",
+            "[day01]\npart3 = 1",
+            "[day01]\npart1 = \"unterminated",
+            "[day01]\npart1",
+        ];
+
+        for case in cases {
+            assert!(
+                AnswerStore::parse(case).is_err(),
+                "Expected error for {case:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use utils::solution::Part;
+
+pub const USAGE: &str = "\
+Usage: aoc <COMMAND>
+
+Commands:
+  run [DAY] [OPTIONS]     Run one day, or every day if DAY is omitted
+  verify [DAY] [OPTIONS]  Check one day, or every day, against the recorded answers
+  bench [DAY] [OPTIONS]   Time each stage of one day, or every day, over many iterations
+  help                    Print this message
+
+Run options:
+  -p, --part <1|2>        Only run the given part
+  -i, --input <PATH>      Read the input from PATH, or stdin if PATH is '-'. Requires a DAY.
+                          Defaults to inputs/dayNN.txt
+
+Verify options:
+  -a, --answers <PATH>    Read the recorded answers from PATH. Defaults to inputs/answers.toml
+
+Bench options:
+  -n, --iterations <N>    Run each day N times. Defaults to 10
+      --json              Print the results as JSON, with durations in nanoseconds";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Help,
+}
+
+pub struct RunArgs {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+}
+
+pub struct VerifyArgs {
+    pub day: Option<u8>,
+    pub answers: Option<String>,
+}
+
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    pub iterations: usize,
+    pub json: bool,
+}
+
+pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let command = args.next().ok_or("No command given".to_string())?;
+
+    match command.as_str() {
+        "run" => parse_run(args).map(Command::Run),
+        "verify" => parse_verify(args).map(Command::Verify),
+        "bench" => parse_bench(args).map(Command::Bench),
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => Err(format!("Unknown command '{command}'")),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs {
+        day: None,
+        part: None,
+        input: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                run_args.part = Some(parse_part(&next_value(&arg, &mut args)?)?);
+            }
+            "-i" | "--input" => {
+                run_args.input = Some(next_value(&arg, &mut args)?);
+            }
+            _ if run_args.day.is_none() => {
+                run_args.day = Some(parse_day(&arg)?);
+            }
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+    }
+
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err("An input can only be given when running a single day".into());
+    }
+
+    Ok(run_args)
+}
+
+fn parse_verify<I: Iterator<Item = String>>(mut args: I) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs {
+        day: None,
+        answers: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-a" | "--answers" => {
+                verify_args.answers = Some(next_value(&arg, &mut args)?);
+            }
+            _ if verify_args.day.is_none() => {
+                verify_args.day = Some(parse_day(&arg)?);
+            }
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+    }
+
+    Ok(verify_args)
+}
+
+fn parse_bench<I: Iterator<Item = String>>(mut args: I) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        iterations: DEFAULT_ITERATIONS,
+        json: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-n" | "--iterations" => {
+                let value = next_value(&arg, &mut args)?;
+                bench_args.iterations = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => {
+                        return Err(format!(
+                            "Expected a positive number of iterations, found '{value}'"
+                        ))
+                    }
+                };
+            }
+            "--json" => bench_args.json = true,
+            _ if bench_args.day.is_none() => {
+                bench_args.day = Some(parse_day(&arg)?);
+            }
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+    }
+
+    Ok(bench_args)
+}
+
+fn next_value<I: Iterator<Item = String>>(arg: &str, args: &mut I) -> Result<String, String> {
+    args.next().ok_or(format!("Expected a value for '{arg}'"))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Expected a day between 1 and 25, found '{value}'")),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Expected part to be 1 or 2, found '{value}'")),
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use utils::{solution::DynSolution, ParseError};
+
+/// Summary of how long a single stage took across every iteration.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "Expected at least one sample");
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 0 {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"min_ns": {}, "median_ns": {}, "max_ns": {}}}"#,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Runs a solution against the input the given number of times, timing each stage separately.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let timings = solution.run(input, None)?.timings;
+
+        parse.push(timings.parse);
+        part1.push(timings.part1.expect("Part 1 was run"));
+        part2.push(timings.part2.expect("Part 2 was run"));
+    }
+
+    Ok(DayBench {
+        day: solution.day(),
+        iterations,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+/// Renders the results as a JSON array with one object per day, with all durations in nanoseconds.
+pub fn to_json(results: &[DayBench]) -> String {
+    let days = results
+        .iter()
+        .map(|r| {
+            format!(
+                r#"  {{"day": {}, "iterations": {}, "parse": {}, "part1": {}, "part2": {}}}"#,
+                r.day,
+                r.iterations,
+                r.parse.to_json(),
+                r.part1.to_json(),
+                r.part2.to_json()
+            )
+        })
+        .collect::<Vec<String>>();
+
+    format!("[\n{}\n]", days.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    pub fn stats() {
+        let ms = Duration::from_millis;
+
+        let odd = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            },
+            odd
+        );
+
+        let even = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            },
+            even
+        );
+    }
+}
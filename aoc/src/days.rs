@@ -0,0 +1,26 @@
+use utils::solution::DynSolution;
+
+/// Every solved day, in order.
+pub const DAYS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+];
+
+/// Selects a single day, or every day if none is given.
+pub fn select(day: Option<u8>) -> Result<Vec<&'static dyn DynSolution>, String> {
+    match day {
+        None => Ok(DAYS.to_vec()),
+        Some(number) => DAYS
+            .iter()
+            .find(|d| d.day() == number)
+            .map(|d| vec![*d])
+            .ok_or(format!("No solution for day {number}")),
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    any::Any,
+    env,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use answers::AnswerStore;
+use args::{BenchArgs, Command, RunArgs, VerifyArgs};
+use table::Table;
+use utils::{
+    input::{self, InputSource},
+    solution::Part,
+};
+
+mod answers;
+mod args;
+mod bench;
+mod days;
+mod table;
+
+fn main() -> ExitCode {
+    let command = match args::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}\n\n{}", args::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Help => {
+            println!("{}", args::USAGE);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let mut table = Table::new(&["Day", "Part 1", "Part 2", "Time"]);
+
+    for solution in days::select(args.day)? {
+        let input = InputSource::from_arg(args.input.as_deref(), solution.day())
+            .load()
+            .map_err(|e| e.to_string())?;
+
+        let report = solution
+            .run(&input, args.part)
+            .map_err(|e| format!("Failed to parse input for day {}: {e}", solution.day()))?;
+
+        table.push(vec![
+            solution.day().to_string(),
+            report.answers.part1.unwrap_or("-".into()),
+            report.answers.part2.unwrap_or("-".into()),
+            format!("{:.2?}", report.timings.total()),
+        ]);
+    }
+
+    print!("{table}");
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let answers_path = args
+        .answers
+        .map(PathBuf::from)
+        .unwrap_or(input::inputs_dir().join(answers::ANSWERS_FILE));
+    let store = AnswerStore::load(&answers_path)?;
+
+    let mut table = Table::new(&["Day", "Part 1", "Part 2"]);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let solutions = days::select(args.day)?;
+
+    // A panic is reported in the table instead, so the default message would only get in the way.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for solution in solutions {
+        let answers = InputSource::Day(solution.day())
+            .load()
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input, None)))
+                    .map_err(|payload| format!("Panicked: {}", panic_message(&*payload)))?
+                    .map_err(|e| format!("Failed to parse input: {e}"))
+            });
+
+        // A day that can't run fails both parts, but the other days are still checked.
+        let answers = match answers {
+            Ok(report) => report.answers,
+            Err(e) => {
+                failed += 2;
+                table.push(vec![
+                    solution.day().to_string(),
+                    format!("FAIL: {e}"),
+                    "FAIL".to_string(),
+                ]);
+                continue;
+            }
+        };
+
+        let mut row = vec![solution.day().to_string()];
+
+        for (part, actual) in [(Part::One, answers.part1), (Part::Two, answers.part2)] {
+            let actual = actual.unwrap_or_default();
+
+            let status = match store.get(solution.day(), part) {
+                Some(expected) if expected == actual => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                Some(expected) => {
+                    failed += 1;
+                    format!("FAIL: expected {expected}, got {actual}")
+                }
+                None => {
+                    missing += 1;
+                    format!("missing, got {actual}")
+                }
+            };
+
+            row.push(status);
+        }
+
+        table.push(row);
+    }
+
+    panic::set_hook(hook);
+
+    print!("{table}");
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+
+    if failed + missing > 0 {
+        return Err("Not every answer could be verified".into());
+    }
+
+    Ok(())
+}
+
+/// The message a panic was raised with, if it was given one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "no message"
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let mut results = Vec::new();
+
+    for solution in days::select(args.day)? {
+        let input = InputSource::Day(solution.day())
+            .load()
+            .map_err(|e| e.to_string())?;
+
+        let result = bench::bench(solution, &input, args.iterations)
+            .map_err(|e| format!("Failed to parse input for day {}: {e}", solution.day()))?;
+        results.push(result);
+    }
+
+    if args.json {
+        println!("{}", bench::to_json(&results));
+        return Ok(());
+    }
+
+    let mut table = Table::new(&["Day", "Stage", "Min", "Median", "Max"]);
+
+    for result in &results {
+        let stages = [
+            ("parse", &result.parse),
+            ("part 1", &result.part1),
+            ("part 2", &result.part2),
+        ];
+
+        for (name, stats) in stages {
+            table.push(vec![
+                result.day.to_string(),
+                name.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            ]);
+        }
+    }
+
+    print!("{table}");
+    Ok(())
+}
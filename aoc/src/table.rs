@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+/// A plain text table, with each column sized to fit its widest cell.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(
+            self.headers.len(),
+            row.len(),
+            "Row has wrong number of cells"
+        );
+        self.rows.push(row);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = (0..self.headers.len())
+            .map(|col| {
+                self.rows
+                    .iter()
+                    .map(|row| row[col].chars().count())
+                    .chain(std::iter::once(self.headers[col].chars().count()))
+                    .max()
+                    .unwrap()
+            })
+            .collect::<Vec<usize>>();
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: &[String]| {
+            for (cell, width) in row.iter().zip(&widths) {
+                write!(f, "| {cell:<width$} ")?;
+            }
+            writeln!(f, "|")
+        };
+
+        write_row(f, &self.headers)?;
+        for width in &widths {
+            write!(f, "|{}", "-".repeat(width + 2))?;
+        }
+        writeln!(f, "|")?;
+
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
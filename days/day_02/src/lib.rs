@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use utils::{
+    parse::{self, delimited, integer, map, one_of, pair, separated, separated_pair, tag},
+    parse_lines,
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+fn part1(games: &[Game]) -> u32 {
+    let full_set = Set {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    games
+        .iter()
+        .filter(|g| g.is_possible(&full_set))
+        .map(|g| g.id)
+        .sum::<u32>()
+}
+
+fn part2(games: &[Game]) -> u32 {
+    games.iter().map(|g| g.power()).sum::<u32>()
+}
+
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    fn is_possible(&self, full_set: &Set) -> bool {
+        self.sets
+            .iter()
+            .all(|s| full_set.blue >= s.blue && full_set.green >= s.green && full_set.red >= s.red)
+    }
+
+    fn power(&self) -> u32 {
+        let minimum_set = self
+            .sets
+            .iter()
+            .fold(Set::default(), |acc, elem| acc.minimum_set(elem));
+
+        minimum_set.red * minimum_set.green * minimum_set.blue
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let balls = separated_pair(
+            integer::<u32>(),
+            tag(" "),
+            one_of(&["red", "green", "blue"]),
+        );
+
+        let set = map(separated(balls, tag(", ")), |balls| {
+            let mut set = Set::default();
+
+            for (count, color) in balls {
+                match color {
+                    "blue" => set.blue = count,
+                    "green" => set.green = count,
+                    "red" => set.red = count,
+                    _ => unreachable!("Colors are checked by the parser"),
+                }
+            }
+
+            set
+        });
+
+        let game = pair(
+            delimited(tag("Game "), integer::<u32>(), tag(": ")),
+            separated(set, tag("; ")),
+        );
+
+        let (id, sets) = parse::finish(s, game)?;
+        Ok(Game { id, sets })
+    }
+}
+
+#[derive(Default)]
+struct Set {
+    blue: u32,
+    green: u32,
+    red: u32,
+}
+
+impl Set {
+    fn minimum_set(&self, other: &Set) -> Set {
+        Set {
+            blue: u32::max(self.blue, other.blue),
+            green: u32::max(self.green, other.green),
+            red: u32::max(self.red, other.red),
+        }
+    }
+}
+
+utils::example_tests! {
+    Day02,
+    input: include_str!("../test-input.txt"),
+    part1: 8,
+    part2: 2286,
+}
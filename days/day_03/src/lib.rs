@@ -0,0 +1,135 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use utils::{direction::Direction, grid::*, solution::Solution, ParseError};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer = u32;
+
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Schematic::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+fn part1(schematic: &Schematic) -> u32 {
+    schematic.find_part_nums().iter().sum::<u32>()
+}
+
+fn part2(schematic: &Schematic) -> u32 {
+    schematic.find_gear_ratios().iter().sum::<u32>()
+}
+
+pub struct Schematic(Grid<Repr>);
+
+impl Schematic {
+    fn find_gear_ratios(&self) -> Vec<u32> {
+        let mut symbol_map: HashMap<Coords, Vec<u32>> = HashMap::new();
+
+        for (num, digits) in self.numbers() {
+            let symbols = digits
+                .iter()
+                .flat_map(|&coords| self.adjacent_symbols(coords))
+                .collect::<HashSet<_>>();
+
+            for coords in symbols {
+                symbol_map.entry(coords).or_default().push(num);
+            }
+        }
+
+        symbol_map
+            .into_values()
+            .filter(|nums| nums.len() == 2)
+            .map(|nums| nums[0] * nums[1])
+            .collect()
+    }
+
+    fn find_part_nums(&self) -> Vec<u32> {
+        self.numbers()
+            .filter(|(_, digits)| {
+                digits
+                    .iter()
+                    .any(|&coords| self.adjacent_symbols(coords).next().is_some())
+            })
+            .map(|(num, _)| num)
+            .collect()
+    }
+
+    /// Each number in the schematic, along with the coordinates of its digits.
+    fn numbers(&self) -> impl Iterator<Item = (u32, Vec<Coords>)> + '_ {
+        let digits = self.0.runs(self.0.lines(Direction::Right), |repr| {
+            matches!(repr, Repr::Number(_))
+        });
+
+        digits.map(|run| {
+            let num = run.iter().fold(0, |num, &coords| match self.0[coords] {
+                Repr::Number(n) => num * 10 + n,
+                _ => num,
+            });
+
+            (num, run)
+        })
+    }
+
+    fn adjacent_symbols(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        self.0
+            .neighbours8(coords)
+            .filter(|&possible| matches!(self.0[possible], Repr::Symbol))
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, Repr::from).map(Schematic)
+    }
+}
+
+enum Repr {
+    Empty,
+    Symbol,
+    Number(u32),
+}
+
+impl Display for Repr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Repr::Empty => write!(f, "."),
+            Repr::Symbol => write!(f, "#"),
+            Repr::Number(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+impl From<char> for Repr {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Repr::Empty,
+            '0'..='9' => Repr::Number(value.to_digit(10).unwrap()),
+            _ => Repr::Symbol,
+        }
+    }
+}
+
+utils::example_tests! {
+    Day03,
+    input: include_str!("../test-input.txt"),
+    part1: 4361,
+    part2: 467835,
+}
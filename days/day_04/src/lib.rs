@@ -0,0 +1,113 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use utils::{
+    parse::{
+        self, delimited, integer, map, pair, separated_pair, spaces, tag, whitespace_separated,
+    },
+    parse_lines,
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+fn part1(cards: &[Card]) -> u32 {
+    cards.iter().map(|c| c.value()).sum::<u32>()
+}
+
+fn part2(cards: &[Card]) -> u32 {
+    // Go in reverse order calculating the total number of scratchcards you'd earn for that
+    // scratchcard. Since the cards won are always 'downwards', we only have to calculate
+    // each one once.
+    let mut cards_per_card: HashMap<u32, u32> = HashMap::new();
+
+    for card in cards.iter().rev() {
+        let winning = card.winning_count();
+        // Sum up the value of any copied cards
+        let copied_count = (0..winning)
+            .map(|offset| card.id + 1 + offset as u32)
+            .map(|id| *cards_per_card.get(&id).unwrap())
+            .sum::<u32>();
+
+        cards_per_card.insert(card.id, copied_count + 1);
+    }
+
+    // We have 1 of each card, so lets just sum them up.
+    cards_per_card.values().sum::<u32>()
+}
+
+pub struct Card {
+    id: u32,
+    winning_nums: HashSet<u32>,
+    my_nums: HashSet<u32>,
+}
+
+impl Card {
+    fn winning_count(&self) -> usize {
+        self.winning_nums.intersection(&self.my_nums).count()
+    }
+
+    fn value(&self) -> u32 {
+        let overlap = self.winning_count();
+
+        if overlap == 0 {
+            return 0;
+        }
+
+        2u32.pow(overlap as u32 - 1)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nums = || map(whitespace_separated(integer::<u32>()), HashSet::from_iter);
+
+        let card = pair(
+            delimited(
+                pair(tag("Card"), spaces()),
+                integer::<u32>(),
+                pair(tag(":"), spaces()),
+            ),
+            separated_pair(nums(), delimited(spaces(), tag("|"), spaces()), nums()),
+        );
+
+        let (id, (winning_nums, my_nums)) = parse::finish(s, card)?;
+
+        Ok(Card {
+            id,
+            winning_nums,
+            my_nums,
+        })
+    }
+}
+
+utils::example_tests! {
+    Day04,
+    input: include_str!("../test-input.txt"),
+    part1: 13,
+    part2: 30,
+}
@@ -0,0 +1,231 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use utils::{
+    parse::{
+        self, integer, map, preceded, separated_pair, tag, take_while1, terminated, verify,
+        whitespace_separated,
+    },
+    ranges::{RangeMap, RangeSet},
+    solution::Solution,
+    ParseError, Section,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer = u64;
+
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+fn part1(almanac: &Almanac) -> u64 {
+    let mut lowest = u64::MAX;
+
+    for seed in &almanac.seeds {
+        lowest = lowest.min(almanac.location_from_seed(*seed));
+    }
+
+    lowest
+}
+
+fn part2(almanac: &Almanac) -> u64 {
+    let seeds = almanac
+        .seeds
+        .chunks(2)
+        .map(|seed_range| seed_range[0]..seed_range[0] + seed_range[1])
+        .collect();
+
+    almanac
+        .locations_from_seeds(seeds)
+        .min()
+        .expect("Expected at least one seed range")
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    property_maps: HashMap<String, PropertyMap>,
+}
+
+impl Almanac {
+    fn location_from_seed(&self, mut value: u64) -> u64 {
+        let mut property = "seed";
+
+        while property != "location" {
+            let map = &self
+                .property_maps
+                .get(property)
+                .expect("Failed to find mapping from property");
+
+            value = map.ranges.map(value);
+            property = &map.dest;
+        }
+
+        value
+    }
+
+    /// Translates a set of seeds into the set of locations they end up in.
+    fn locations_from_seeds(&self, mut values: RangeSet<u64>) -> RangeSet<u64> {
+        let mut property = "seed";
+
+        while property != "location" {
+            let map = &self
+                .property_maps
+                .get(property)
+                .expect("Failed to find mapping from property");
+
+            values = map.ranges.map_set(&values);
+            property = &map.dest;
+        }
+
+        values
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = utils::sections(s);
+
+        let seeds = sections
+            .next()
+            .ok_or(ParseError::at(s, s, "'seeds: <seeds>'"))?
+            .parse(preceded(
+                tag("seeds: "),
+                verify(
+                    whitespace_separated(integer::<u64>()),
+                    "pairs of a seed number and a non-zero length",
+                    |seeds| seeds.len() % 2 == 0 && seeds.chunks(2).all(|pair| pair[1] > 0),
+                ),
+            ))?;
+
+        let mut property_maps = HashMap::new();
+        let mut headers = HashMap::new();
+
+        for section in sections {
+            let header = section.split_first().0.text();
+            let map = PropertyMap::from_section(section)?;
+
+            if property_maps.contains_key(&map.source) {
+                let expected = format!("the only map from '{}'", map.source);
+                return Err(ParseError::at(s, header, expected));
+            }
+
+            headers.insert(map.source.clone(), header);
+            property_maps.insert(map.source.clone(), map);
+        }
+
+        // Following the maps from seeds has to lead to locations, without going round in circles,
+        // for every seed to have a location.
+        let mut property = "seed";
+        let mut seen = HashSet::from([property]);
+        let mut previous = &s[s.len()..];
+
+        while property != "location" {
+            let Some(map) = property_maps.get(property) else {
+                let expected = format!("a map from '{property}' to follow");
+                return Err(ParseError::at(s, previous, expected));
+            };
+
+            previous = headers[property];
+
+            if !seen.insert(&map.dest) {
+                let expected = "a map to a property that hasn't already been mapped from";
+                return Err(ParseError::at(s, previous, expected));
+            }
+
+            property = &map.dest;
+        }
+
+        Ok(Almanac {
+            seeds,
+            property_maps,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct PropertyMap {
+    source: String,
+    dest: String,
+    ranges: RangeMap<u64>,
+}
+
+impl PropertyMap {
+    /// Parses a block like `seed-to-soil map:` followed by lines of `<dest> <source> <length>`.
+    fn from_section(section: Section) -> Result<Self, ParseError> {
+        let (header, body) = section.split_first();
+
+        let property = || {
+            map(
+                take_while1("a property name", char::is_alphabetic),
+                String::from,
+            )
+        };
+        let (source, dest) = header.parse(terminated(
+            separated_pair(property(), tag("-to-"), property()),
+            tag(" map:"),
+        ))?;
+
+        let mut ranges = RangeMap::new();
+
+        for mapping in body.parse_lines::<Mapping>()? {
+            ranges.insert(
+                mapping.source..mapping.source + mapping.length,
+                mapping.dest,
+            );
+        }
+
+        Ok(PropertyMap {
+            source,
+            dest,
+            ranges,
+        })
+    }
+}
+
+struct Mapping {
+    dest: u64,
+    source: u64,
+    length: u64,
+}
+
+impl FromStr for Mapping {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let num = integer::<u64>;
+        let mapping = separated_pair(num(), tag(" "), separated_pair(num(), tag(" "), num()));
+        let (dest, (source, length)) = parse::finish(s, mapping)?;
+
+        Ok(Mapping {
+            dest,
+            source,
+            length,
+        })
+    }
+}
+
+utils::example_tests! {
+    Day05,
+    input: include_str!("../test-input.txt"),
+    part1: 35,
+    part2: 46,
+}
@@ -0,0 +1,112 @@
+use utils::{
+    parse::{
+        self, integer, newline, pair, preceded, separated_pair, spaces, tag, whitespace_separated,
+    },
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+    type Answer = u64;
+
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+fn part1(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|r| r.count_possible_ways())
+        .product::<u64>()
+}
+
+fn part2(races: &[Race]) -> u64 {
+    // Dumb way to combine them, stringify and then reparse.
+    let time = races
+        .iter()
+        .map(|r| r.duration.to_string())
+        .fold(String::new(), |acc, elem| acc + &elem)
+        .parse::<u64>()
+        .expect("Expected number");
+
+    let dist = races
+        .iter()
+        .map(|r| r.record_distance.to_string())
+        .fold(String::new(), |acc, elem| acc + &elem)
+        .parse::<u64>()
+        .expect("Expected number");
+
+    Race {
+        duration: time,
+        record_distance: dist,
+    }
+    .count_possible_ways()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let numbers = || whitespace_separated(integer::<u64>());
+    let races = separated_pair(
+        preceded(pair(tag("Time:"), spaces()), numbers()),
+        newline(),
+        preceded(pair(tag("Distance:"), spaces()), numbers()),
+    );
+
+    let (times, distances) = parse::finish(input, races)?;
+
+    if times.len() != distances.len() {
+        let distance_line = input.lines().nth(1).unwrap_or_default();
+        return Err(ParseError::at(
+            input,
+            distance_line,
+            format!("{} distances to match the times", times.len()),
+        ));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race {
+            duration: time,
+            record_distance: distance,
+        })
+        .collect())
+}
+
+pub struct Race {
+    duration: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    fn count_possible_ways(&self) -> u64 {
+        // Effectively solving quadratic formula where a = -1, b = duration, c = -record_distance
+        let base = self.duration as f64 / 2.0;
+        let offset = 0.5 * (self.duration.pow(2) as f64 - 4.0 * self.record_distance as f64).sqrt();
+
+        let start = (1.0 + base - offset).trunc() as u64;
+        let end = (base + offset - 1.0).ceil() as u64;
+
+        1 + end - start
+    }
+}
+
+utils::example_tests! {
+    Day06,
+    input: include_str!("../test-input.txt"),
+    part1: 288,
+    part2: 71503,
+}
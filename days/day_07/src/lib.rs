@@ -0,0 +1,215 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use utils::{
+    parse::{self, integer, separated_pair, tag, take_while1, verify},
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Answer = usize;
+
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        utils::parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        get_result::<JokerlessRuleset>(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        get_result::<JokerRuleset>(input)
+    }
+}
+
+fn get_result<T: Ruleset>(hands: &[Hand]) -> usize {
+    let mut hands = hands.to_vec();
+    sort::<T>(&mut hands);
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| (idx + 1) * hand.bid as usize)
+        .sum::<usize>()
+}
+
+trait Ruleset {
+    const ORDERING: [char; 13];
+
+    fn evaluate(hand: &Hand) -> HandKind;
+}
+
+fn sort<T: Ruleset>(cards: &mut [Hand]) {
+    cards.sort_by(|first, second| {
+        let first_hand = T::evaluate(first);
+        let second_hand = T::evaluate(second);
+
+        let hand_comparison = first_hand.cmp(&second_hand);
+
+        if let Ordering::Equal = hand_comparison {
+            let zipped = first.cards.iter().zip(second.cards.iter());
+            for (own_char, other_char) in zipped {
+                let own_idx = T::ORDERING.iter().position(|c| c == own_char).unwrap();
+                let other_idx = T::ORDERING.iter().position(|c| c == other_char).unwrap();
+
+                match own_idx.cmp(&other_idx) {
+                    Ordering::Equal => continue,
+                    Ordering::Less => return Ordering::Greater,
+                    Ordering::Greater => return Ordering::Less,
+                }
+            }
+        }
+
+        hand_comparison
+    });
+}
+
+struct JokerlessRuleset;
+
+impl Ruleset for JokerlessRuleset {
+    const ORDERING: [char; 13] = [
+        'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+    ];
+
+    fn evaluate(hand: &Hand) -> HandKind {
+        let mut card_counts = HashMap::new();
+
+        for c in &hand.cards {
+            let count = card_counts.entry(c).or_insert(0u32);
+            *count += 1;
+        }
+
+        match card_counts.len() {
+            5 => HandKind::HighCard,
+            4 => HandKind::OnePair,
+            3 => {
+                // Its either two pair or three of a kind
+                let max = *card_counts.values().max().unwrap();
+                if max == 3 {
+                    HandKind::ThreeOfAKind
+                } else {
+                    HandKind::TwoPair
+                }
+            }
+            2 => {
+                // Its either full house or 4 of a kind
+                let max = *card_counts.values().max().unwrap();
+                if max == 4 {
+                    HandKind::FourOfAKind
+                } else {
+                    HandKind::FullHouse
+                }
+            }
+            1 => HandKind::FiveOfAKind,
+            _ => panic!("Found unexpected number of unique cards"),
+        }
+    }
+}
+
+struct JokerRuleset;
+
+impl Ruleset for JokerRuleset {
+    const ORDERING: [char; 13] = [
+        'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
+    ];
+
+    fn evaluate(hand: &Hand) -> HandKind {
+        let mut card_counts = HashMap::new();
+        let mut non_joker_count = 0;
+
+        for c in &hand.cards {
+            if *c == 'J' {
+                continue;
+            }
+
+            non_joker_count += 1;
+            let count = card_counts.entry(c).or_insert(0u32);
+            *count += 1;
+        }
+
+        match non_joker_count {
+            5 => JokerlessRuleset::evaluate(hand),
+            4 => match card_counts.len() {
+                1 => HandKind::FiveOfAKind,
+                2 => {
+                    // Currently could have 2 pairs or 3 of a kind
+                    let max = *card_counts.values().max().unwrap();
+                    if max == 3 {
+                        HandKind::FourOfAKind
+                    } else {
+                        HandKind::FullHouse
+                    }
+                }
+                3 => HandKind::ThreeOfAKind,
+                4 => HandKind::OnePair,
+                _ => panic!("Unexpected number of unique cards"),
+            },
+            3 => match card_counts.len() {
+                1 => HandKind::FiveOfAKind,
+                2 => HandKind::FourOfAKind,
+                3 => HandKind::ThreeOfAKind,
+                _ => panic!("Unexpected number of unique cards"),
+            },
+            2 => {
+                // If we have a pair -> can still make 5 of a kind
+                if card_counts.len() == 1 {
+                    HandKind::FiveOfAKind
+                } else {
+                    HandKind::FourOfAKind
+                }
+            }
+            1 => HandKind::FiveOfAKind,
+            0 => HandKind::FiveOfAKind,
+            _ => panic!("Unexpected non-joker count"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: u32,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_card = |c: char| JokerlessRuleset::ORDERING.contains(&c);
+        let cards = verify(
+            take_while1("a hand of 5 cards", is_card),
+            "a hand of 5 cards",
+            |cards| cards.chars().count() == 5,
+        );
+
+        let (cards, bid) = parse::finish(s, separated_pair(cards, tag(" "), integer::<u32>()))?;
+
+        Ok(Hand {
+            cards: cards.chars().collect(),
+            bid,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+utils::example_tests! {
+    Day07,
+    input: include_str!("../test-input.txt"),
+    part1: 6440,
+    part2: 5905,
+}
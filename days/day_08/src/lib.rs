@@ -0,0 +1,192 @@
+use std::{collections::HashMap, iter};
+
+use utils::{
+    cycles::{self, Cycle},
+    nums,
+    parse::{delimited, map, newline, separated, separated_pair, tag, take_while1},
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Instruction>, HashMap<String, Node>);
+    type Answer = usize;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(&input.0, &input.1)
+    }
+}
+
+fn part1(instructions: &[Instruction], nodes: &HashMap<String, Node>) -> usize {
+    assert!(nodes.contains_key("AAA"), "Expected a node named AAA");
+
+    // There are only so many distinct states, so if the walk hasn't reached ZZZ by the time it has
+    // been through all of them, it's going round a loop that never will.
+    let states = nodes.len() * instructions.len();
+
+    iter::successors(Some(("AAA", 0)), |&state| {
+        Some(step(instructions, nodes, state))
+    })
+    .take(states)
+    .position(|(node, _)| node == "ZZZ")
+    .expect("Expected ZZZ to be reachable from AAA")
+}
+
+fn part2(instructions: &[Instruction], nodes: &HashMap<String, Node>) -> usize {
+    let cycles = nodes
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|start| {
+            cycles::hashed(
+                (start.as_str(), 0),
+                |&state| step(instructions, nodes, state),
+                |&(node, _)| node.ends_with('Z'),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    first_common_target(&cycles).expect("Expected the ghosts to reach end nodes together")
+}
+
+/// Takes a single step of a walk, where each state is the current node along with the position in
+/// the instructions.
+fn step<'a>(
+    instructions: &[Instruction],
+    nodes: &'a HashMap<String, Node>,
+    (node, idx): (&str, usize),
+) -> (&'a str, usize) {
+    let node = &nodes[node];
+    let next = match instructions[idx] {
+        Instruction::Left => node.left.as_str(),
+        Instruction::Right => node.right.as_str(),
+    };
+
+    (next, (idx + 1) % instructions.len())
+}
+
+/// Finds the first step at which every walk reaches a target at the same time.
+fn first_common_target(cycles: &[Cycle]) -> Option<usize> {
+    let settled = cycles.iter().map(|c| c.prefix).max()?;
+
+    // Until every walk is in its loop, just check each step in turn.
+    if let Some(step) = (0..settled).find(|&step| cycles.iter().all(|c| c.is_target(step))) {
+        return Some(step);
+    }
+
+    // From then on, a walk reaches a target at every step congruent to one of its targets in the
+    // loop, modulo the loop's length. Each choice of one target per walk gives a system of
+    // congruences to solve.
+    let mut systems = vec![(0, 1)];
+
+    for cycle in cycles {
+        systems = systems
+            .into_iter()
+            .flat_map(|system| {
+                cycle.cycle_targets().filter_map(move |offset| {
+                    let target = (cycle.prefix + offset) as i64;
+                    nums::crt([system, (target, cycle.length as i64)])
+                })
+            })
+            .collect();
+    }
+
+    systems
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let settled = settled as i64;
+            (settled + (residue - settled).rem_euclid(modulus)) as usize
+        })
+        .min()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Instruction>, HashMap<String, Node>), ParseError> {
+    let mut sections = utils::sections(input);
+    let end = &input[input.len()..];
+
+    let instructions = sections
+        .next()
+        .ok_or(ParseError::at(input, end, "a line of instructions"))?
+        .parse(map(
+            take_while1("'L' or 'R'", |c| c == 'L' || c == 'R'),
+            |line| {
+                line.chars()
+                    .map(|c| match c {
+                        'L' => Instruction::Left,
+                        _ => Instruction::Right,
+                    })
+                    .collect()
+            },
+        ))?;
+
+    let section =
+        sections
+            .next()
+            .ok_or(ParseError::at(input, end, "a blank line followed by nodes"))?;
+
+    // The names are kept as slices of the input until every node is known, so that a node that's
+    // defined twice, or a name that leads nowhere, can be pointed at.
+    let name = || take_while1("a node name", char::is_alphanumeric);
+    let node = separated_pair(
+        name(),
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(name(), tag(", "), name()),
+            tag(")"),
+        ),
+    );
+    let definitions = section.parse(separated(node, newline()))?;
+
+    let mut nodes = HashMap::new();
+
+    for &(ident, (left, right)) in &definitions {
+        let node = Node {
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+
+        if nodes.insert(ident.to_string(), node).is_some() {
+            let expected = "the name of a node that isn't already defined";
+            return Err(ParseError::at(input, ident, expected));
+        }
+    }
+
+    // A walk can't continue from a node that isn't defined.
+    if let Some(missing) = definitions
+        .iter()
+        .flat_map(|&(_, (left, right))| [left, right])
+        .find(|name| !nodes.contains_key(*name))
+    {
+        return Err(ParseError::at(input, missing, "the name of a defined node"));
+    }
+
+    Ok((instructions, nodes))
+}
+
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+pub struct Node {
+    left: String,
+    right: String,
+}
+
+utils::example_tests! {
+    Day08,
+    part1: (include_str!("../test-input.txt"), 2),
+    part2: (include_str!("../test-input-2.txt"), 6),
+}
@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use utils::{
+    parse::{self, integer, whitespace_separated},
+    solution::Solution,
+    ParseError,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Sequence>;
+    type Answer = i64;
+
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        utils::parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|s| s.extrapolate().1).sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|s| s.extrapolate().0).sum::<i64>()
+    }
+}
+
+pub struct Sequence(Vec<i64>);
+
+impl Sequence {
+    fn extrapolate(&self) -> (i64, i64) {
+        let mut sequences = vec![self.0.clone()];
+
+        // Descend the values
+        loop {
+            let last = sequences.last().unwrap();
+
+            if last.iter().all(|v| *v == 0) {
+                break;
+            }
+
+            let next = last
+                .windows(2)
+                .map(|window| window[1] - window[0])
+                .collect();
+            sequences.push(next);
+        }
+
+        let mut next = 0;
+        let mut prev = 0;
+        for sequence in sequences.iter().rev() {
+            next += sequence.last().unwrap();
+            prev = sequence.first().unwrap() - prev;
+        }
+
+        (prev, next)
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nums = parse::finish(s, whitespace_separated(integer::<i64>()))?;
+        Ok(Sequence(nums))
+    }
+}
+
+utils::example_tests! {
+    Day09,
+    input: include_str!("../test-input.txt"),
+    part1: 114,
+    part2: 2,
+}
@@ -73,6 +73,7 @@
                 pushd days/day_$1
                   cargo add --path ../../lib/utils/
                   aoc download -y 2023 -d $1
+                  mv input.txt ../../inputs/day$1.txt
                 popd
               '';
             }];
@@ -0,0 +1,257 @@
+//! Cycle detection for sequences where each state is computed from the previous one, such as a
+//! walk through a puzzle's state machine. Any such sequence over finitely many states eventually
+//! repeats itself: it runs through a prefix of states that are never seen again, then goes round
+//! the same loop forever.
+//!
+//! Each detector also records the steps at which the sequence reaches a target state, which is
+//! enough to know every later step that reaches one.
+
+use std::{collections::HashMap, hash::Hash, iter};
+
+/// The shape of a sequence that eventually repeats, found by one of the detectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the sequence first enters the loop.
+    pub prefix: usize,
+    /// The number of steps it takes to go round the loop once.
+    pub length: usize,
+    /// Every step, counted from the start, that reaches a target state before the sequence first
+    /// comes back round to the start of the loop. These are in ascending order.
+    pub targets: Vec<usize>,
+}
+
+impl Cycle {
+    /// Maps a step onto the step before the end of the first time round the loop that reaches
+    /// the same state.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.length
+        }
+    }
+
+    /// Whether the given step reaches a target state.
+    pub fn is_target(&self, step: usize) -> bool {
+        self.targets
+            .binary_search(&self.equivalent_step(step))
+            .is_ok()
+    }
+
+    /// The targets reached once the sequence is in the loop, as offsets from the start of the loop.
+    pub fn cycle_targets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.targets
+            .iter()
+            .filter(|&&step| step >= self.prefix)
+            .map(|step| step - self.prefix)
+    }
+}
+
+/// Floyd's tortoise and hare, which needs only a couple of states in memory at once.
+pub fn floyd<S>(start: S, mut next: impl FnMut(&S) -> S, is_target: impl FnMut(&S) -> bool) -> Cycle
+where
+    S: Clone + PartialEq,
+{
+    // Find a step that is inside the loop and a multiple of its length.
+    let mut tortoise = next(&start);
+    let mut hare = next(&tortoise);
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        let halfway = next(&hare);
+        hare = next(&halfway);
+    }
+
+    // Walking from the start and from that step together, they first meet at the loop's start.
+    let mut prefix = 0;
+    tortoise = start.clone();
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+
+    let targets = targets(start, next, is_target, prefix + length);
+    Cycle {
+        prefix,
+        length,
+        targets,
+    }
+}
+
+/// Brent's algorithm, which needs as little memory as Floyd's but fewer calls to `next`.
+pub fn brent<S>(start: S, mut next: impl FnMut(&S) -> S, is_target: impl FnMut(&S) -> bool) -> Cycle
+where
+    S: Clone + PartialEq,
+{
+    // Find the loop's length by moving the tortoise up to the hare at each power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // With the hare a whole loop ahead, they first meet at the loop's start.
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start.clone();
+
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+
+    let targets = targets(start, next, is_target, prefix + length);
+    Cycle {
+        prefix,
+        length,
+        targets,
+    }
+}
+
+/// Remembers the step at which each state was seen until one comes round again. This walks the
+/// sequence only once, at the cost of keeping every state in memory.
+pub fn hashed<S>(
+    start: S,
+    mut next: impl FnMut(&S) -> S,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Cycle
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut targets = Vec::new();
+    let mut state = start;
+
+    for step in 0.. {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle {
+                prefix: first,
+                length: step - first,
+                targets,
+            };
+        }
+
+        if is_target(&state) {
+            targets.push(step);
+        }
+
+        let following = next(&state);
+        seen.insert(state, step);
+        state = following;
+    }
+
+    unreachable!("Expected the sequence to repeat")
+}
+
+/// Walks the first `steps` steps from the start again, collecting those that reach a target.
+fn targets<S>(
+    start: S,
+    mut next: impl FnMut(&S) -> S,
+    mut is_target: impl FnMut(&S) -> bool,
+    steps: usize,
+) -> Vec<usize> {
+    iter::successors(Some(start), |state| Some(next(state)))
+        .take(steps)
+        .enumerate()
+        .filter(|(_, state)| is_target(state))
+        .map(|(step, _)| step)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, floyd, hashed, Cycle};
+    use crate::testing::Rng;
+
+    #[test]
+    pub fn detection() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 3 -> ...
+        let table = [1, 2, 3, 4, 5, 3];
+        let next = |&s: &usize| table[s];
+        let even = |&s: &usize| s % 2 == 0;
+
+        let expected = Cycle {
+            prefix: 3,
+            length: 3,
+            targets: vec![0, 2, 4],
+        };
+        assert_eq!(expected, floyd(0, next, even));
+        assert_eq!(expected, brent(0, next, even));
+        assert_eq!(expected, hashed(0, next, even));
+
+        // Starting inside the loop leaves no prefix.
+        let cycle = hashed(4, next, even);
+        assert_eq!((0, 3, vec![0]), (cycle.prefix, cycle.length, cycle.targets));
+
+        let single = hashed(7, |&s: &u32| s, |_| true);
+        assert_eq!(
+            (0, 1, vec![0]),
+            (single.prefix, single.length, single.targets)
+        );
+    }
+
+    #[test]
+    pub fn later_steps() {
+        let table = [1, 2, 3, 4, 5, 3];
+        let cycle = hashed(0, |&s: &usize| table[s], |&s| s == 4);
+
+        assert_eq!(vec![1], cycle.cycle_targets().collect::<Vec<_>>());
+        assert_eq!(2, cycle.equivalent_step(2));
+        assert_eq!(4, cycle.equivalent_step(7));
+        assert_eq!(
+            vec![4, 7, 10, 13],
+            (0..15).filter(|&s| cycle.is_target(s)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn detectors_agree() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..500 {
+            let size = 1 + rng.below(40) as usize;
+            let table = (0..size)
+                .map(|_| rng.below(size as u64) as usize)
+                .collect::<Vec<_>>();
+            let start = rng.below(size as u64) as usize;
+            let next = |&s: &usize| table[s];
+            let is_target = |&s: &usize| s % 3 == 0;
+
+            let expected = hashed(start, next, is_target);
+            assert_eq!(expected, floyd(start, next, is_target));
+            assert_eq!(expected, brent(start, next, is_target));
+
+            // Walking the sequence directly agrees with the cycle about every step.
+            let mut state = start;
+            for step in 0..3 * size {
+                assert_eq!(is_target(&state), expected.is_target(step));
+                state = next(&state);
+            }
+        }
+    }
+}
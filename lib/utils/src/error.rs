@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+/// An error from parsing puzzle input, describing what was expected, what was found instead and,
+/// where known, the 1-based line and column it was found at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error with no position information.
+    pub fn new(expected: impl Into<String>, found: &str) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for `found`, positioned by where it sits within `source`. If `found` isn't
+    /// a slice of `source` then the position is left unknown.
+    pub fn at(source: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let mut error = ParseError::new(expected, found);
+
+        let start = source.as_ptr() as usize;
+        let offset = (found.as_ptr() as usize).wrapping_sub(start);
+
+        let within = offset <= source.len() && found.len() <= source.len() - offset;
+
+        if within && source.is_char_boundary(offset) {
+            let before = &source[..offset];
+            let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+            error.line = Some(before.matches('\n').count() + 1);
+            error.column = Some(before[line_start..].chars().count() + 1);
+        }
+
+        error
+    }
+
+    /// Adjusts the position of an error from parsing `part`, a slice of `source`, to be relative
+    /// to `source` instead. An error without a position is placed at the start of `part`.
+    pub fn within(mut self, source: &str, part: &str) -> ParseError {
+        let base = ParseError::at(source, part, "");
+
+        let (Some(base_line), Some(base_column)) = (base.line, base.column) else {
+            return self;
+        };
+
+        match (self.line, self.column) {
+            (Some(1) | None, column) => {
+                self.line = Some(base_line);
+                self.column = Some(base_column + column.unwrap_or(1) - 1);
+            }
+            (Some(line), _) => self.line = Some(base_line + line - 1),
+        }
+
+        self
+    }
+
+    /// Adjusts the position of an error from parsing some text that started on the given line of
+    /// a larger input.
+    pub fn offset(mut self, line: usize) -> ParseError {
+        self.line = Some(self.line.map_or(line, |l| line + l - 1));
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+
+        write!(f, "expected {}", self.expected)?;
+
+        if self.found.is_empty() {
+            write!(f, ", found nothing")
+        } else {
+            write!(f, ", found '{}'", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    pub fn at() {
+        let source = "Time: 7 15\nDistance: 9 4x 200";
+        let found = &source[23..25];
+
+        let error = ParseError::at(source, found, "a number");
+        assert_eq!(Some(2), error.line);
+        assert_eq!(Some(13), error.column);
+        assert_eq!(
+            "line 2, column 13: expected a number, found '4x'",
+            error.to_string()
+        );
+
+        let error = ParseError::at(source, &String::from("4x"), "a number");
+        assert_eq!(None, error.line);
+        assert_eq!("expected a number, found '4x'", error.to_string());
+    }
+
+    #[test]
+    pub fn offset() {
+        let error = ParseError::new("a number", "x").offset(5);
+        assert_eq!("line 5: expected a number, found 'x'", error.to_string());
+
+        let error = ParseError::at("a\nb\nx", "a\nb\nx".split('\n').nth(2).unwrap(), "y");
+        assert_eq!(Some(3), error.line);
+        assert_eq!(Some(12), error.offset(10).line);
+    }
+
+    #[test]
+    pub fn within() {
+        let source = "Game 1: 3 blue; 4 rde";
+        let part = &source[16..];
+
+        let error = ParseError::at(part, &part[2..], "a color").within(source, part);
+        assert_eq!((Some(1), Some(19)), (error.line, error.column));
+
+        let error = ParseError::new("a set", part).within(source, part);
+        assert_eq!((Some(1), Some(17)), (error.line, error.column));
+    }
+}
@@ -1,4 +1,19 @@
-use std::ops::Index;
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    direction::{Direction, Direction8},
+    point::Point,
+    ParseError,
+};
+
+mod regions;
+
+pub use regions::{Components, Connectivity};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Coords {
@@ -6,57 +21,375 @@ pub struct Coords {
     pub y: usize,
 }
 
+/// A rectangular grid of cells, stored row by row in a single buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
-    inner: Vec<Vec<T>>,
-    height: usize,
+    cells: Vec<T>,
     width: usize,
+    height: usize,
 }
 
 impl<T> Grid<T> {
-    pub fn new(inner: Vec<Vec<T>>) -> Grid<T> {
-        let height = inner.len();
-        let width = if height != 0 { inner[0].len() } else { 0 };
+    /// Builds a grid from its rows.
+    ///
+    /// Panics if the rows are not all the same width.
+    pub fn new(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Expected every row of the grid to be {width} cells wide"
+        );
+
+        let height = rows.len();
+        Grid::from_vec(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Builds a `width` by `height` grid from its cells in row-major order.
+    ///
+    /// Panics if there aren't exactly `width * height` cells.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Expected the cells to fill a {width}x{height} grid"
+        );
 
         Grid {
-            inner,
-            height,
+            cells,
             width,
+            height,
         }
     }
 
-    pub fn adjacent_coords(&self, coords: Coords) -> Vec<Coords> {
-        let mut adjacent = vec![];
+    /// Builds a grid by calling `f` with the coordinates of each cell, in row-major order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Coords) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coords { x, y }))
+            .map(&mut f)
+            .collect();
 
-        for dx in [-1isize, 0, 1] {
-            for dy in [-1isize, 0, 1] {
-                if dy == 0 && dx == 0 {
-                    continue;
-                }
+        Grid::from_vec(width, height, cells)
+    }
 
-                let x = coords.x as isize + dx;
-                let y = coords.y as isize + dy;
+    /// Parses a grid with one row per line, mapping each character to a cell. Fails if the rows
+    /// aren't all the same width.
+    pub fn parse(data: &str, cell: impl Fn(char) -> T) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::with_capacity(data.len());
+        let mut width = None;
+        let mut height = 0;
 
-                if y < 0 || y >= self.height as isize {
-                    continue;
+        for line in data.trim_end_matches(['\r', '\n']).lines() {
+            let row_start = cells.len();
+            cells.extend(line.chars().map(&cell));
+            let row_width = cells.len() - row_start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let expected = format!("a row {width} cells wide");
+                    return Err(ParseError::at(data, line, expected));
                 }
+                Some(_) => {}
+            }
 
-                let y = y as usize;
+            height += 1;
+        }
+
+        Ok(Grid::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        coords.x < self.width && coords.y < self.height
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        self.index_of(coords).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
+        self.index_of(coords).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
 
-                if x < 0 || x >= self.width as isize {
-                    continue;
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.height).then(|| &mut self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of a column, from top to bottom.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// Replaces the cell at `coords`, returning the old value, or `None` if `coords` is outside
+    /// the grid.
+    pub fn set(&mut self, coords: Coords, value: T) -> Option<T> {
+        self.get_mut(coords)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    /// Panics if either coordinate is outside the grid.
+    pub fn swap(&mut self, a: Coords, b: Coords) {
+        let (a, b) = (self.index_or_panic(a), self.index_or_panic(b));
+        self.cells.swap(a, b);
+    }
+
+    /// Builds a grid of the same size by mapping each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// The cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The cells in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells in row-major order, along with their coordinates.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coords, &T)> {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(idx, cell)| {
+            let coords = Coords {
+                x: idx % width,
+                y: idx / width,
+            };
+            (coords, cell)
+        })
+    }
+
+    /// A borrowed view of the `width` by `height` rectangle whose top-left corner is at `origin`,
+    /// or `None` if the rectangle doesn't fit within the grid.
+    pub fn view(&self, origin: Coords, width: usize, height: usize) -> Option<GridView<'_, T>> {
+        let fits = origin.x + width <= self.width && origin.y + height <= self.height;
+
+        fits.then_some(GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+
+    /// Renders the grid as text using `cell` to format each cell, ready to be highlighted.
+    pub fn render<F, D>(&self, cell: F) -> Render<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        Render {
+            grid: self,
+            cell,
+            highlighted: HashSet::new(),
+            marker: None,
+        }
+    }
+
+    /// The coordinates of a point, if it lies within the grid.
+    pub fn coords_of(&self, point: Point) -> Option<Coords> {
+        Coords::try_from(point).ok().filter(|&c| self.contains(c))
+    }
+
+    /// The coordinates one step away in the given direction, unless that is off the grid.
+    pub fn step(&self, coords: Coords, direction: impl Into<Point>) -> Option<Coords> {
+        self.coords_of(Point::from(coords) + direction.into())
+    }
+
+    /// The up to four coordinates orthogonally adjacent to `coords`, clockwise from up.
+    pub fn neighbours4(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(coords, dir))
+    }
+
+    /// The up to eight coordinates orthogonally or diagonally adjacent to `coords`, clockwise from
+    /// up.
+    pub fn neighbours8(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(coords, dir))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = (usize, &[T])> {
+        (0..self.height).map(|y| (y, &self.cells[y * self.width..(y + 1) * self.width]))
+    }
+
+    /// The cells of each column, from top to bottom, along with the column's index.
+    pub fn columns(&self) -> impl Iterator<Item = (usize, impl Iterator<Item = &T>)> {
+        (0..self.width).map(|x| (x, self.cells.iter().skip(x).step_by(self.width)))
+    }
+
+    /// Every coordinate in the grid, in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coords> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coords { x, y }))
+    }
+
+    /// The coordinates from `start` onwards in a straight line, stopping at the edge of the grid.
+    /// `start` itself is included if it is within the grid.
+    pub fn ray(
+        &self,
+        start: Coords,
+        direction: impl Into<Point>,
+    ) -> impl Iterator<Item = Coords> + '_ {
+        let offset = direction.into();
+        assert!(offset != Point::ORIGIN, "A ray needs a non-zero direction");
+
+        std::iter::successors(self.contains(start).then_some(start), move |&c| {
+            self.step(c, offset)
+        })
+    }
+
+    /// Every maximal straight line across the grid in the given direction, so `Direction::Right`
+    /// gives the rows and `Direction8::DownRight` the diagonals. Lines are ordered by where they
+    /// start, in row-major order.
+    pub fn lines(
+        &self,
+        direction: impl Into<Point>,
+    ) -> impl Iterator<Item = impl Iterator<Item = Coords> + '_> + '_ {
+        let offset = direction.into();
+
+        self.coords()
+            .filter(move |&c| self.coords_of(Point::from(c) - offset).is_none())
+            .map(move |start| self.ray(start, offset))
+    }
+
+    /// The diagonals running down and to the right.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Coords> + '_> + '_ {
+        self.lines(Direction8::DownRight)
+    }
+
+    /// The diagonals running down and to the left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Coords> + '_> + '_ {
+        self.lines(Direction8::DownLeft)
+    }
+
+    /// Finds each maximal run of consecutive cells along the lines that satisfy the predicate,
+    /// such as the numbers in `grid.runs(grid.lines(Direction::Right), char::is_ascii_digit)`.
+    /// Runs never continue from one line onto the next.
+    pub fn runs<'a, L>(
+        &'a self,
+        lines: impl IntoIterator<Item = L> + 'a,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Vec<Coords>> + 'a
+    where
+        L: IntoIterator<Item = Coords>,
+        L::IntoIter: 'a,
+    {
+        let mut lines = lines.into_iter();
+        let mut line: Option<L::IntoIter> = None;
+
+        std::iter::from_fn(move || loop {
+            let current = match &mut line {
+                Some(current) => current,
+                None => line.insert(lines.next()?.into_iter()),
+            };
+
+            let mut run = Vec::new();
+
+            for coords in current.by_ref() {
+                if predicate(&self[coords]) {
+                    run.push(coords);
+                } else if !run.is_empty() {
+                    return Some(run);
                 }
+            }
 
-                let x = x as usize;
+            line = None;
 
-                adjacent.push(Coords { x, y })
+            if !run.is_empty() {
+                return Some(run);
             }
+        })
+    }
+
+    fn index_of(&self, coords: Coords) -> Option<usize> {
+        self.contains(coords)
+            .then_some(coords.y * self.width + coords.x)
+    }
+
+    fn index_or_panic(&self, coords: Coords) -> usize {
+        match self.index_of(coords) {
+            Some(idx) => idx,
+            None => panic!(
+                "{coords:?} is outside a {}x{} grid",
+                self.width, self.height
+            ),
         }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, mirroring the grid across its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coords { x: c.y, y: c.x }].clone()
+        })
+    }
 
-        adjacent
+    /// Rotates a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coords {
+                x: c.y,
+                y: self.height - 1 - c.x,
+            }]
+            .clone()
+        })
     }
 
-    pub fn rows(&self) -> impl Iterator<Item = (usize, &Vec<T>)> {
-        self.inner.iter().enumerate()
+    /// Rotates a quarter turn anticlockwise, so the right column becomes the top row.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coords {
+                x: self.width - 1 - c.y,
+                y: c.x,
+            }]
+            .clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coords {
+                x: self.width - 1 - c.x,
+                y: c.y,
+            }]
+            .clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coords {
+                x: c.x,
+                y: self.height - 1 - c.y,
+            }]
+            .clone()
+        })
     }
 }
 
@@ -64,6 +397,478 @@ impl<T> Index<Coords> for Grid<T> {
     type Output = T;
 
     fn index(&self, coords: Coords) -> &Self::Output {
-        &self.inner[coords.y][coords.x]
+        &self.cells[self.index_or_panic(coords)]
+    }
+}
+
+impl<T> IndexMut<Coords> for Grid<T> {
+    fn index_mut(&mut self, coords: Coords) -> &mut Self::Output {
+        let idx = self.index_or_panic(coords);
+        &mut self.cells[idx]
+    }
+}
+
+/// A rectangular window onto a [`Grid`], created by [`Grid::view`]. Coordinates are relative to
+/// the top-left corner of the window.
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coords,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&'a T> {
+        let within = coords.x < self.width && coords.y < self.height;
+        within.then(|| &self.grid[self.to_grid_coords(coords)])
+    }
+
+    /// The coordinates within the underlying grid of a cell in the view.
+    pub fn to_grid_coords(&self, coords: Coords) -> Coords {
+        Coords {
+            x: self.origin.x + coords.x,
+            y: self.origin.y + coords.y,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = (usize, &'a [T])> + '_ {
+        (0..self.height).map(|y| {
+            let row = self.grid.row(self.origin.y + y).unwrap();
+            (y, &row[self.origin.x..self.origin.x + self.width])
+        })
+    }
+
+    /// Copies the cells of the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |c| self[c].clone())
+    }
+}
+
+impl<T> Index<Coords> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, coords: Coords) -> &Self::Output {
+        match self.get(coords) {
+            Some(cell) => cell,
+            None => panic!(
+                "{coords:?} is outside a {}x{} view",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A grid formatted for display, one row per line, created by [`Grid::render`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+    highlighted: HashSet<Coords>,
+    marker: Option<char>,
+}
+
+impl<T, F> Render<'_, T, F> {
+    /// Highlights the cells at the given coordinates, drawing them in reverse video.
+    pub fn highlight(mut self, coords: impl IntoIterator<Item = Coords>) -> Self {
+        self.highlighted.extend(coords);
+        self
+    }
+
+    /// Draws highlighted cells as the marker instead, for output that won't reach a terminal.
+    pub fn marker(mut self, marker: char) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+}
+
+impl<T, F, D> Display for Render<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, cell) in row.iter().enumerate() {
+                let cell = (self.cell)(cell);
+
+                match (self.highlighted.contains(&Coords { x, y }), self.marker) {
+                    (false, _) => write!(f, "{cell}")?,
+                    (true, Some(marker)) => write!(f, "{marker}")?,
+                    (true, None) => write!(f, "\x1b[7m{cell}\x1b[0m")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Coords, Grid};
+    use crate::direction::{Direction, Direction8};
+
+    fn grid() -> Grid<u32> {
+        Grid::new(vec![vec![0, 1, 2], vec![3, 4, 5]])
+    }
+
+    #[test]
+    pub fn parse() {
+        let grid = Grid::parse("012\r\n345\r\n\r\n", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(5, grid[Coords { x: 2, y: 1 }]);
+
+        let grid = Grid::<char>::from_str("ab\ncd").unwrap();
+        assert_eq!('c', grid[Coords { x: 0, y: 1 }]);
+
+        let error = Grid::<char>::from_str("ab\ncd\ne\nfg").unwrap_err();
+        assert_eq!(
+            "line 3, column 1: expected a row 2 cells wide, found 'e'",
+            error.to_string()
+        );
+
+        let grid = Grid::<char>::from_str("").unwrap();
+        assert_eq!((0, 0), (grid.width(), grid.height()));
+        assert_eq!(0, grid.rows().count());
+    }
+
+    #[test]
+    pub fn access() {
+        let mut grid = grid();
+
+        assert_eq!(Some(&4), grid.get(Coords { x: 1, y: 1 }));
+        assert_eq!(None, grid.get(Coords { x: 3, y: 0 }));
+        assert_eq!(None, grid.get(Coords { x: 0, y: 2 }));
+
+        *grid.get_mut(Coords { x: 2, y: 0 }).unwrap() = 9;
+        grid[Coords { x: 0, y: 1 }] += 10;
+        assert_eq!(None, grid.get_mut(Coords { x: 3, y: 1 }));
+
+        assert_eq!(Some(&[0, 1, 9][..]), grid.row(0));
+        assert_eq!(Some(&[13, 4, 5][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+        assert_eq!(Some(vec![&9, &5]), grid.column(2).map(|c| c.collect()));
+        assert!(grid.column(3).is_none());
+
+        grid.row_mut(1).unwrap().fill(7);
+        let rows = grid.rows().map(|(_, row)| row.to_vec()).collect::<Vec<_>>();
+        assert_eq!(vec![vec![0, 1, 9], vec![7, 7, 7]], rows);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn index_out_of_bounds() {
+        // Past the end of the first row, which must not wrap around onto the second.
+        let _ = grid()[Coords { x: 3, y: 0 }];
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn ragged_rows() {
+        Grid::new(vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    pub fn degenerate() {
+        let flat = Grid::from_fn(3, 0, |_| 0);
+        assert_eq!((3, 0), (flat.width(), flat.height()));
+        assert_eq!(Some(0), flat.column(2).map(Iterator::count));
+        assert!(flat.column(3).is_none());
+        assert_eq!(0, flat.rows().count());
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0)],
+            flat.columns()
+                .map(|(x, c)| (x, c.count()))
+                .collect::<Vec<_>>()
+        );
+
+        let thin = Grid::from_fn(0, 3, |_| 0);
+        assert_eq!((0, 3), (thin.width(), thin.height()));
+        assert_eq!(thin, Grid::new(vec![vec![], vec![], vec![]]));
+        assert_eq!(thin, Grid::from_vec(0, 3, vec![]));
+        assert_eq!(
+            vec![0, 0, 0],
+            thin.rows().map(|(_, r)| r.len()).collect::<Vec<_>>()
+        );
+        assert!(thin.column(0).is_none());
+        assert_eq!(0, thin.columns().count());
+
+        assert_eq!(thin, flat.transpose());
+        assert_eq!(flat, flat.transpose().transpose());
+        assert_eq!(thin, flat.rotate_cw());
+        assert_eq!(flat, flat.rotate_cw().rotate_ccw());
+        assert_eq!(flat, flat.map(|&n| n + 1));
+
+        let empty = Grid::<char>::from_str("").unwrap();
+        assert_eq!((0, 0), (empty.width(), empty.height()));
+    }
+
+    #[test]
+    pub fn mutation() {
+        let mut grid = grid();
+
+        assert_eq!(Some(4), grid.set(Coords { x: 1, y: 1 }, 8));
+        assert_eq!(None, grid.set(Coords { x: 1, y: 2 }, 8));
+        grid.swap(Coords { x: 0, y: 0 }, Coords { x: 2, y: 1 });
+        grid.iter_mut().for_each(|cell| *cell *= 2);
+        assert_eq!(
+            vec![10, 2, 4, 6, 16, 0],
+            grid.iter().copied().collect::<Vec<_>>()
+        );
+
+        let odd = grid.map(|cell| cell % 4 != 0);
+        assert_eq!((3, 2), (odd.width(), odd.height()));
+        assert!(odd[Coords { x: 0, y: 0 }]);
+        assert!(!odd[Coords { x: 2, y: 0 }]);
+
+        grid.fill(1);
+        assert!(grid.iter().all(|&cell| cell == 1));
+    }
+
+    #[test]
+    pub fn enumerate() {
+        let grid = grid();
+
+        for (coords, &cell) in grid.enumerate() {
+            assert_eq!(grid[coords], cell);
+        }
+
+        let last = grid.enumerate().last();
+        assert_eq!(Some((Coords { x: 2, y: 1 }, &5)), last);
+        assert_eq!(6, grid.enumerate().count());
+    }
+
+    #[test]
+    pub fn transforms() {
+        let grid = grid();
+
+        let rows = |grid: &Grid<u32>| grid.rows().map(|(_, row)| row.to_vec()).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![vec![0, 3], vec![1, 4], vec![2, 5]],
+            rows(&grid.transpose())
+        );
+        assert_eq!(
+            vec![vec![3, 0], vec![4, 1], vec![5, 2]],
+            rows(&grid.rotate_cw())
+        );
+        assert_eq!(
+            vec![vec![2, 5], vec![1, 4], vec![0, 3]],
+            rows(&grid.rotate_ccw())
+        );
+        assert_eq!(
+            vec![vec![2, 1, 0], vec![5, 4, 3]],
+            rows(&grid.flip_horizontal())
+        );
+        assert_eq!(
+            vec![vec![3, 4, 5], vec![0, 1, 2]],
+            rows(&grid.flip_vertical())
+        );
+    }
+
+    #[test]
+    pub fn transform_round_trips() {
+        let grid = Grid::from_fn(4, 3, |c| c.y * 4 + c.x);
+
+        assert_eq!(grid, grid.transpose().transpose());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+        assert_eq!(grid, grid.rotate_ccw().rotate_cw());
+        assert_eq!(grid, grid.flip_horizontal().flip_horizontal());
+        assert_eq!(grid, grid.flip_vertical().flip_vertical());
+
+        let spun = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(grid, spun);
+        assert_eq!(grid.rotate_cw(), grid.transpose().flip_horizontal());
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(
+            grid,
+            grid.view(Coords { x: 0, y: 0 }, 4, 3).unwrap().to_grid()
+        );
+    }
+
+    #[test]
+    pub fn views() {
+        let grid = Grid::from_fn(4, 3, |c| c.y * 4 + c.x);
+        let view = grid.view(Coords { x: 1, y: 1 }, 2, 2).unwrap();
+
+        assert_eq!((2, 2), (view.width(), view.height()));
+        assert_eq!(5, view[Coords { x: 0, y: 0 }]);
+        assert_eq!(Some(&10), view.get(Coords { x: 1, y: 1 }));
+        assert_eq!(None, view.get(Coords { x: 2, y: 0 }));
+        assert_eq!(
+            Coords { x: 2, y: 2 },
+            view.to_grid_coords(Coords { x: 1, y: 1 })
+        );
+
+        let rows = view.rows().map(|(_, row)| row.to_vec()).collect::<Vec<_>>();
+        assert_eq!(vec![vec![5, 6], vec![9, 10]], rows);
+        assert_eq!(Grid::new(rows), view.to_grid());
+
+        assert!(grid.view(Coords { x: 3, y: 0 }, 2, 1).is_none());
+        assert!(grid.view(Coords { x: 0, y: 2 }, 1, 2).is_none());
+        assert!(grid.view(Coords { x: 4, y: 3 }, 0, 0).is_some());
+    }
+
+    fn values<T: Copy>(
+        grid: &Grid<T>,
+        lines: impl Iterator<Item = impl Iterator<Item = Coords>>,
+    ) -> Vec<Vec<T>> {
+        lines.map(|line| line.map(|c| grid[c]).collect()).collect()
+    }
+
+    #[test]
+    pub fn lines() {
+        let grid = Grid::from_fn(3, 2, |c| c.y * 3 + c.x);
+
+        let columns = grid
+            .columns()
+            .map(|(_, column)| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![0, 3], vec![1, 4], vec![2, 5]], columns);
+
+        assert_eq!(
+            vec![vec![0, 1, 2], vec![3, 4, 5]],
+            values(&grid, grid.lines(Direction::Right))
+        );
+        assert_eq!(
+            vec![vec![2, 1, 0], vec![5, 4, 3]],
+            values(&grid, grid.lines(Direction::Left))
+        );
+        assert_eq!(
+            vec![vec![0, 4], vec![1, 5], vec![2], vec![3]],
+            values(&grid, grid.diagonals())
+        );
+        assert_eq!(
+            vec![vec![0], vec![1, 3], vec![2, 4], vec![5]],
+            values(&grid, grid.anti_diagonals())
+        );
+
+        let ray = grid
+            .ray(Coords { x: 1, y: 1 }, Direction::Left)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Coords { x: 1, y: 1 }, Coords { x: 0, y: 1 }], ray);
+        assert_eq!(0, grid.ray(Coords { x: 3, y: 0 }, Direction::Left).count());
+    }
+
+    #[test]
+    pub fn runs() {
+        let grid = Grid::<char>::from_str("12.3\n4..5\n.67.").unwrap();
+        let strings =
+            |runs: Vec<Vec<char>>| runs.into_iter().map(String::from_iter).collect::<Vec<_>>();
+
+        let rows = grid.runs(grid.lines(Direction::Right), char::is_ascii_digit);
+        assert_eq!(
+            vec!["12", "3", "4", "5", "67"],
+            strings(values(&grid, rows.map(Vec::into_iter)))
+        );
+
+        let columns = grid.runs(grid.lines(Direction::Down), char::is_ascii_digit);
+        assert_eq!(
+            vec!["14", "2", "6", "7", "35"],
+            strings(values(&grid, columns.map(Vec::into_iter)))
+        );
+
+        let mut dots = grid.runs(grid.lines(Direction::Right), |&c| c == '.');
+        assert_eq!(
+            Some(vec![Coords { x: 1, y: 1 }, Coords { x: 2, y: 1 }]),
+            dots.nth(1)
+        );
+    }
+
+    #[test]
+    pub fn render() {
+        let grid = Grid::<char>::from_str("ab.\n.cd").unwrap();
+        assert_eq!("ab.\n.cd", grid.to_string());
+        assert_eq!("", Grid::<char>::new(vec![]).to_string());
+
+        let upper = grid.render(|c| c.to_ascii_uppercase());
+        assert_eq!("AB.\n.CD", upper.to_string());
+
+        let path = [Coords { x: 1, y: 0 }, Coords { x: 1, y: 1 }];
+        let marked = grid.render(|&c| c).highlight(path).marker('#');
+        assert_eq!("a#.\n.#d", marked.to_string());
+
+        let nums = Grid::new(vec![vec![1, 22], vec![3, 4]]);
+        let highlighted = nums.render(|&n| n).highlight([Coords { x: 1, y: 0 }]);
+        assert_eq!("1\x1b[7m22\x1b[0m\n34", highlighted.to_string());
+    }
+
+    #[test]
+    pub fn neighbours() {
+        let grid = grid();
+        let values = |coords: Vec<Coords>| coords.iter().map(|&c| grid[c]).collect::<Vec<_>>();
+
+        let corner = Coords { x: 0, y: 0 };
+        assert_eq!(vec![1, 3], values(grid.neighbours4(corner).collect()));
+        assert_eq!(vec![1, 4, 3], values(grid.neighbours8(corner).collect()));
+
+        let edge = Coords { x: 1, y: 1 };
+        assert_eq!(vec![1, 5, 3], values(grid.neighbours4(edge).collect()));
+        assert_eq!(
+            vec![1, 2, 5, 3, 0],
+            values(grid.neighbours8(edge).collect())
+        );
+    }
+
+    #[test]
+    pub fn step() {
+        let grid = grid();
+        let coords = Coords { x: 2, y: 0 };
+
+        assert_eq!(
+            Some(Coords { x: 1, y: 0 }),
+            grid.step(coords, Direction::Left)
+        );
+        assert_eq!(
+            Some(Coords { x: 1, y: 1 }),
+            grid.step(coords, Direction8::DownLeft)
+        );
+        assert_eq!(None, grid.step(coords, Direction::Up));
+        assert_eq!(None, grid.step(coords, Direction::Right));
+        assert_eq!(None, grid.step(coords, Direction8::DownRight));
     }
 }
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use super::{Coords, Grid};
+use crate::direction::Direction8;
+
+/// Which cells count as adjacent when finding regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    fn directions(self) -> impl Iterator<Item = Direction8> {
+        // The cardinal directions are every other entry, starting from up.
+        let step = match self {
+            Connectivity::Four => 2,
+            Connectivity::Eight => 1,
+        };
+
+        Direction8::ALL.into_iter().step_by(step)
+    }
+}
+
+/// The connected regions of a grid, as found by [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The label of the component each cell belongs to. Labels count up from 0 in the order the
+    /// components are first met, reading the grid in row-major order.
+    pub labels: Grid<usize>,
+    /// The number of cells in each component, indexed by label.
+    pub sizes: Vec<usize>,
+}
+
+impl Components {
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// The cells in the component with the given label, in row-major order.
+    pub fn cells(&self, label: usize) -> impl Iterator<Item = Coords> + '_ {
+        self.labels
+            .coords()
+            .filter(move |&c| self.labels[c] == label)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds every cell that can be reached from `seed` by moving between cells that satisfy the
+    /// predicate. The result is empty if the seed itself doesn't satisfy it.
+    pub fn flood_fill(
+        &self,
+        seed: Coords,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> HashSet<Coords> {
+        let mut filled = HashSet::new();
+
+        if !self.get(seed).is_some_and(&predicate) {
+            return filled;
+        }
+
+        filled.insert(seed);
+        let mut stack = vec![seed];
+
+        while let Some(coords) = stack.pop() {
+            for dir in connectivity.directions() {
+                let Some(next) = self.step(coords, dir) else {
+                    continue;
+                };
+
+                if predicate(&self[next]) && filled.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Splits the whole grid into connected components, where two adjacent cells are part of the
+    /// same component if `joins` holds for them. Use `|a, b| a == b` to find regions of equal
+    /// cells.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        joins: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        let mut labels: Grid<Option<usize>> = self.map(|_| None);
+        let mut sizes = Vec::new();
+
+        for seed in self.coords() {
+            if labels[seed].is_some() {
+                continue;
+            }
+
+            let label = sizes.len();
+            let mut size = 1;
+            labels[seed] = Some(label);
+            let mut stack = vec![seed];
+
+            while let Some(coords) = stack.pop() {
+                for dir in connectivity.directions() {
+                    let Some(next) = self.step(coords, dir) else {
+                        continue;
+                    };
+
+                    if labels[next].is_none() && joins(&self[coords], &self[next]) {
+                        labels[next] = Some(label);
+                        size += 1;
+                        stack.push(next);
+                    }
+                }
+            }
+
+            sizes.push(size);
+        }
+
+        Components {
+            labels: labels.map(|label| label.expect("Every cell is labelled")),
+            sizes,
+        }
+    }
+
+    /// The cells of a region that are adjacent to a cell outside it, or to the edge of the grid,
+    /// in row-major order.
+    pub fn boundary(&self, region: &HashSet<Coords>, connectivity: Connectivity) -> Vec<Coords> {
+        let mut boundary = region
+            .iter()
+            .copied()
+            .filter(|&c| {
+                connectivity
+                    .directions()
+                    .any(|dir| match self.step(c, dir) {
+                        Some(n) => !region.contains(&n),
+                        None => true,
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        boundary.sort_by_key(|c| (c.y, c.x));
+        boundary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr};
+
+    use super::Connectivity;
+    use crate::grid::{Coords, Grid};
+
+    const MAP: &str = "\
+aab.
+abb.
+..c.
+.c..";
+
+    fn map() -> Grid<char> {
+        Grid::from_str(MAP).unwrap()
+    }
+
+    fn coords(cells: &[(usize, usize)]) -> HashSet<Coords> {
+        cells.iter().map(|&(x, y)| Coords { x, y }).collect()
+    }
+
+    #[test]
+    pub fn flood_fill() {
+        let grid = map();
+        let is_b = |&c: &char| c == 'b';
+        let is_dot = |&c: &char| c == '.';
+
+        let b = grid.flood_fill(Coords { x: 2, y: 0 }, Connectivity::Four, is_b);
+        assert_eq!(coords(&[(2, 0), (1, 1), (2, 1)]), b);
+
+        let dots = grid.flood_fill(Coords { x: 3, y: 0 }, Connectivity::Four, is_dot);
+        assert_eq!(5, dots.len());
+
+        let corner = Coords { x: 0, y: 2 };
+        assert_eq!(3, grid.flood_fill(corner, Connectivity::Four, is_dot).len());
+        assert_eq!(
+            8,
+            grid.flood_fill(corner, Connectivity::Eight, is_dot).len()
+        );
+        assert!(grid.flood_fill(corner, Connectivity::Four, is_b).is_empty());
+    }
+
+    #[test]
+    pub fn components() {
+        let grid = map();
+
+        let four = grid.components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(vec![3, 3, 5, 3, 1, 1], four.sizes);
+        assert_eq!(grid.width() * grid.height(), four.sizes.iter().sum());
+        assert_eq!(1, four.labels[Coords { x: 2, y: 1 }]);
+        assert_eq!(
+            coords(&[(0, 2), (1, 2), (0, 3)]),
+            four.cells(3).collect::<HashSet<_>>()
+        );
+
+        // Touching corners join the two groups of dots, and the two c's.
+        let eight = grid.components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(vec![3, 3, 8, 2], eight.sizes);
+        assert_eq!(4, eight.count());
+
+        let letters = grid.components(Connectivity::Four, |a, b| (*a == '.') == (*b == '.'));
+        assert_eq!(4, letters.count());
+    }
+
+    #[test]
+    pub fn boundary() {
+        let grid = Grid::from_fn(4, 4, |_| '.');
+        let all = grid.coords().collect::<HashSet<_>>();
+
+        let boundary = grid.boundary(&all, Connectivity::Four);
+        assert_eq!(12, boundary.len());
+        assert!(!boundary.contains(&Coords { x: 1, y: 1 }));
+        assert_eq!(Coords { x: 0, y: 0 }, boundary[0]);
+
+        let square = coords(&[(1, 1), (2, 1), (1, 2), (2, 2), (2, 3)]);
+        let expected = vec![
+            Coords { x: 1, y: 1 },
+            Coords { x: 2, y: 1 },
+            Coords { x: 1, y: 2 },
+            Coords { x: 2, y: 2 },
+            Coords { x: 2, y: 3 },
+        ];
+        assert_eq!(expected, grid.boundary(&square, Connectivity::Four));
+
+        let plus = coords(&[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]);
+        let centre = Coords { x: 1, y: 1 };
+        assert!(!grid.boundary(&plus, Connectivity::Four).contains(&centre));
+        assert!(grid.boundary(&plus, Connectivity::Eight).contains(&centre));
+    }
+}
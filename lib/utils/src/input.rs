@@ -0,0 +1,95 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// The directory that puzzle inputs are conventionally stored in, as `dayNN.txt`.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where to load a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file at the given path.
+    File(PathBuf),
+    /// Everything written to stdin.
+    Stdin,
+    /// The conventional input file for the given day, e.g. `inputs/day07.txt`.
+    Day(u8),
+}
+
+impl InputSource {
+    /// Interprets a command line argument as an input source. No argument means the conventional
+    /// input for the day, and `-` means stdin.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> InputSource {
+        match arg {
+            None => InputSource::Day(day),
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.into()),
+        }
+    }
+
+    pub fn load(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_file(path.clone()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Day(day) => read_file(day_path(*day)),
+        }
+    }
+}
+
+/// The conventional path of the input for a day.
+pub fn day_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("day{day:02}.txt"))
+}
+
+/// Finds the inputs directory by looking in the current directory and then each of its ancestors,
+/// so that days can be run from anywhere in the workspace.
+pub fn inputs_dir() -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join(INPUTS_DIR))
+                .find(|dir| dir.is_dir())
+        })
+        .unwrap_or(PathBuf::from(INPUTS_DIR))
+}
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path),
+        _ => InputError::File(path, e),
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    File(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "Input file '{}' does not exist", path.display())
+            }
+            InputError::File(path, e) => {
+                write!(f, "Failed to read input file '{}': {e}", path.display())
+            }
+            InputError::Stdin(e) => write!(f, "Failed to read input from stdin: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
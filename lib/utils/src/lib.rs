@@ -1,21 +1,151 @@
 use std::str::FromStr;
 
+pub mod cycles;
+pub mod direction;
 pub mod grid;
+pub mod input;
 pub mod nums;
+pub mod parse;
+pub mod point;
+pub mod ranges;
+pub mod search;
+pub mod solution;
 
-pub fn parse_lines<T, E>(data: &str) -> Result<Vec<T>, E>
+mod error;
+mod sections;
+#[cfg(test)]
+mod testing;
+
+pub use error::ParseError;
+pub use sections::{sections, Section};
+
+/// What to do with blank lines, those that are empty or hold only whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlankLines {
+    #[default]
+    Skip,
+    /// Parse blank lines like any other line.
+    Keep,
+    /// Fail on the first blank line.
+    Reject,
+}
+
+/// How lines are cleaned up before they are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineOptions {
+    /// Whether to trim whitespace from both ends of each line.
+    pub trim: bool,
+    pub blank_lines: BlankLines,
+}
+
+/// Parses each non-blank line of the data, annotating any error with the line it came from. Both
+/// `\n` and `\r\n` line endings are accepted.
+pub fn parse_lines<T>(data: &str) -> Result<Vec<T>, ParseError>
 where
-    T: FromStr<Err = E>,
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(data, LineOptions::default())
+}
+
+/// Parses each line of the data as configured by the options, annotating any error with the line
+/// it came from. Both `\n` and `\r\n` line endings are accepted.
+pub fn parse_lines_with<T>(data: &str, options: LineOptions) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_iter_with(data.lines(), options)
+}
+
+/// Parses each non-blank line, annotating any error with the line it came from. Lines are numbered
+/// from 1 at the start of the iterator.
+pub fn parse_lines_iter<'a, I, T>(iter: I) -> Result<Vec<T>, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+    T: FromStr<Err = ParseError>,
 {
-    parse_lines_iter(data.split("\n"))
+    parse_lines_iter_with(iter, LineOptions::default())
 }
 
-pub fn parse_lines_iter<'a, I, T, E>(iter: I) -> Result<Vec<T>, E>
+/// Parses each line as configured by the options, annotating any error with the line it came from.
+/// Lines are numbered from 1 at the start of the iterator.
+pub fn parse_lines_iter_with<'a, I, T>(iter: I, options: LineOptions) -> Result<Vec<T>, ParseError>
 where
     I: Iterator<Item = &'a str>,
-    T: FromStr<Err = E>,
+    T: FromStr<Err = ParseError>,
 {
-    iter.filter(|s| !s.is_empty())
-        .map(|s| T::from_str(s))
-        .collect()
+    let mut parsed = Vec::new();
+
+    for (idx, raw) in iter.enumerate() {
+        let line_num = idx + 1;
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        let line = if options.trim { raw.trim() } else { raw };
+
+        if raw.trim().is_empty() {
+            match options.blank_lines {
+                BlankLines::Skip => continue,
+                BlankLines::Keep => {}
+                BlankLines::Reject => {
+                    return Err(ParseError::at(raw, raw, "a non-blank line").offset(line_num));
+                }
+            }
+        }
+
+        let value = T::from_str(line).map_err(|e| {
+            // Columns should count from the start of the untrimmed line.
+            let e = if options.trim { e.within(raw, line) } else { e };
+            e.offset(line_num)
+        })?;
+
+        parsed.push(value);
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_lines, parse_lines_with, BlankLines, LineOptions, ParseError};
+    use crate::testing::Num;
+
+    fn values(nums: Result<Vec<Num>, ParseError>) -> Result<Vec<u32>, String> {
+        nums.map(|nums| nums.iter().map(|n| n.0).collect())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    pub fn line_options() {
+        let data = "1\r\n2\r\n\r\n 3 \r\n";
+
+        assert_eq!(
+            Err("line 4, column 1: expected an integer, found ' 3 '".into()),
+            values(parse_lines(data))
+        );
+
+        let trim = LineOptions {
+            trim: true,
+            ..LineOptions::default()
+        };
+        assert_eq!(Ok(vec![1, 2, 3]), values(parse_lines_with(data, trim)));
+
+        let reject = LineOptions {
+            blank_lines: BlankLines::Reject,
+            ..trim
+        };
+        assert_eq!(
+            Err("line 3, column 1: expected a non-blank line, found nothing".into()),
+            values(parse_lines_with(data, reject))
+        );
+
+        let keep = LineOptions {
+            blank_lines: BlankLines::Keep,
+            ..trim
+        };
+        assert_eq!(
+            Err("line 3, column 1: expected an integer, found nothing".into()),
+            values(parse_lines_with(data, keep))
+        );
+
+        let error = parse_lines_with::<Num>("1\n  2x", trim).unwrap_err();
+        assert_eq!((Some(2), Some(3)), (error.line, error.column));
+    }
 }
@@ -1,21 +1,184 @@
-/// Calculates the greatest common divisor using Euclid's algorithm
-pub fn gcd(mut a: usize, mut b: usize) -> usize {
-    while b > 0 {
-        let tmp = b;
-        b = a % b;
-        a = tmp;
+use std::ops::Div;
+
+/// The primitive integer types, signed or unsigned and of any width, so that the functions here
+/// work with whichever type a puzzle needs.
+pub trait Integer: Copy + Eq + Div<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The remainder, which is 0 rather than an overflow for `MIN % -1`.
+    fn wrapping_rem(self, rhs: Self) -> Self;
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    (signed: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn wrapping_rem(self, rhs: Self) -> Self {
+                <$t>::wrapping_rem(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                <$t>::checked_abs(self)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn wrapping_rem(self, rhs: Self) -> Self {
+                self % rhs
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Calculates the greatest common divisor using Euclid's algorithm. The result is never negative,
+/// and is only 0 if both a and b are.
+///
+/// # Panics
+///
+/// If the result doesn't fit in the type, which only happens when it would be `-MIN`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("Expected the GCD to fit in the type")
+}
+
+/// Calculates the greatest common divisor, or `None` if it would be `-MIN` and so doesn't fit.
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+
+    a.checked_abs()
+}
+
+/// Calculates the least common multiple of a and b. The result is never negative, and is 0 if
+/// either a or b is.
+///
+/// # Panics
+///
+/// If the result doesn't fit in the type.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("Expected the LCM to fit in the type")
+}
+
+/// Calculates the least common multiple, or `None` if it doesn't fit in the type. Only the result
+/// needs to fit, as a is divided by the GCD before multiplying.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a / checked_gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// Calculates the greatest common divisor of all the values, which is 0 if there are none.
+pub fn gcd_all<T: Integer>(values: &[T]) -> T {
+    values.iter().fold(T::ZERO, |acc, &v| gcd(acc, v))
+}
+
+/// Calculates the least common multiple of all the values, which is 1 if there are none.
+///
+/// # Panics
+///
+/// If the result, or the LCM of any of the leading values, doesn't fit in the type.
+pub fn lcm_all<T: Integer>(values: &[T]) -> T {
+    checked_lcm_all(values).expect("Expected the LCM to fit in the type")
+}
+
+/// Calculates the least common multiple of all the values, or `None` if it doesn't fit.
+pub fn checked_lcm_all<T: Integer>(values: &[T]) -> Option<T> {
+    values
+        .iter()
+        .try_fold(T::ONE, |acc, &v| checked_lcm(acc, v))
+}
+
+/// Calculates the greatest common divisor of a and b, along with coefficients x and y such that
+/// `a * x + b * y` equals it. The divisor is never negative.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
     }
 
-    return a;
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Finds x in `0..m` such that `a * x` is congruent to 1 modulo m, which only exists if a and m
+/// are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
 }
 
-/// Calculates the least common multiple of a and b
-pub fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
+/// Solves a system of congruences `x ≡ r (mod m)`, given as `(r, m)` pairs with positive moduli,
+/// using the Chinese Remainder Theorem. The moduli don't need to be coprime.
+///
+/// Returns the solution as a single congruence `(r, m)` with r in `0..m`, where m is the LCM of
+/// the moduli. Returns `None` if the congruences contradict each other, or if the LCM of the moduli
+/// doesn't fit in an i64.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "Expected a positive modulus, found {m2}");
+
+            // Residues can be anywhere in i64, so their difference and the products below can
+            // exceed it before being reduced. Everything is done in i128 instead.
+            let (g, p, _) = extended_gcd(m1, m2);
+            let (g, p) = (g as i128, p as i128);
+            let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, r2 as i128, m2 as i128);
+            let diff = r2 - r1;
+
+            if diff % g != 0 {
+                return None;
+            }
+
+            // Step from r1 in multiples of m1 until also reaching r2 modulo m2.
+            let step_mod = m2 / g;
+            let steps = ((diff / g).rem_euclid(step_mod) * p).rem_euclid(step_mod);
+            let modulus = m1 * step_mod;
+            let residue = (r1 + m1 * steps).rem_euclid(modulus);
+
+            Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+        })
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{checked_gcd, checked_lcm, checked_lcm_all, gcd_all, lcm_all};
+
     #[test]
     pub fn gcd() {
         struct TestCase {
@@ -52,4 +215,121 @@ mod tests {
             assert_eq!(tc.expected, result);
         }
     }
+
+    #[test]
+    pub fn extended_gcd() {
+        for (a, b, expected) in [
+            (240, 46, 2),
+            (46, 240, 2),
+            (17, 5, 1),
+            (0, 7, 7),
+            (-12, 18, 6),
+        ] {
+            let (g, x, y) = super::extended_gcd(a, b);
+            assert_eq!(expected, g);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    pub fn mod_inverse() {
+        assert_eq!(Some(4), super::mod_inverse(3, 11));
+        assert_eq!(Some(7), super::mod_inverse(-3, 11));
+        assert_eq!(Some(0), super::mod_inverse(5, 1));
+        assert_eq!(None, super::mod_inverse(2, 4));
+    }
+
+    #[test]
+    pub fn crt() {
+        assert_eq!(Some((23, 105)), super::crt([(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((20, 24)), super::crt([(2, 6), (4, 8)]));
+        assert_eq!(Some((4, 6)), super::crt([(-2, 6), (10, 3)]));
+        assert_eq!(None, super::crt([(1, 4), (2, 6)]));
+        assert_eq!(Some((0, 1)), super::crt([]));
+
+        // Solutions that only fit once the moduli are combined.
+        let big = 1 << 40;
+        assert_eq!(
+            Some((big + 1, big * 3)),
+            super::crt([(1, big), (big + 1, 3)])
+        );
+
+        // Residues at the ends of the range, whose differences don't fit in an i64.
+        let max = i64::MAX;
+        assert_eq!(
+            Some((max - 1, max)),
+            super::crt([(max - 1, max), (-(max - 1), 1)])
+        );
+        assert_eq!(Some((7, 35)), super::crt([(i64::MIN, 5), (max, 7)]));
+        assert_eq!(None, super::crt([(max - 1, max), (-(max - 1), 3)]));
+
+        // Compare every pair of small congruences against a brute force search.
+        for m1 in 1..10 {
+            for m2 in 1..10 {
+                let modulus = super::lcm(m1, m2);
+
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let expected = (0..modulus)
+                            .find(|x| x % m1 == r1 && x % m2 == r2)
+                            .map(|x| (x, modulus));
+
+                        assert_eq!(expected, super::crt([(r1, m1), (r2, m2)]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn widths() {
+        assert_eq!(6u8, super::gcd(12u8, 18));
+        assert_eq!(6i32, super::gcd(-12, 18));
+        assert_eq!(6i32, super::gcd(12, -18));
+        assert_eq!(7i64, super::gcd(0, -7));
+        assert_eq!(0u16, super::gcd(0, 0));
+        assert_eq!(1u128 << 100, super::gcd(1u128 << 100, 3 << 100));
+
+        assert_eq!(36i16, super::lcm(-12, 18));
+        assert_eq!(0usize, super::lcm(0, 5));
+        assert_eq!(3u128 << 100, super::lcm(1u128 << 100, 3 << 99));
+    }
+
+    #[test]
+    pub fn overflow() {
+        // The result fits, even though the product of the inputs doesn't.
+        let big = 1u64 << 62;
+        assert_eq!(big, super::lcm(big, big / 2));
+        assert_eq!(200u8, super::lcm(200, 100));
+        assert_eq!(Some(i64::MAX), checked_lcm(i64::MAX, i64::MAX));
+
+        assert_eq!(None, checked_lcm(255u8, 254));
+        assert_eq!(None, checked_lcm(i8::MIN, 3));
+        assert_eq!(None, checked_gcd(i32::MIN, 0));
+        assert_eq!(None, checked_gcd(i32::MIN, i32::MIN));
+        assert_eq!(Some(1), checked_gcd(i32::MIN, -1));
+        assert_eq!(Some(1 << 30), checked_gcd(i32::MIN, 1 << 30));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn lcm_overflow() {
+        super::lcm(u32::MAX, u32::MAX - 1);
+    }
+
+    #[test]
+    pub fn slices() {
+        assert_eq!(4, gcd_all(&[12, 8, -20]));
+        assert_eq!(0, gcd_all::<u32>(&[]));
+        assert_eq!(5, gcd_all(&[0, 5, 0]));
+
+        assert_eq!(120, lcm_all(&[8, 12, 15, 5]));
+        assert_eq!(1, lcm_all::<i64>(&[]));
+        assert_eq!(0, lcm_all(&[3, 0, 4]));
+
+        // Step counts like day 8's, whose LCM is far smaller than their product.
+        let steps = [20_777u64, 18_673, 13_939, 17_621, 19_199, 15_517];
+        assert_eq!(22_561_435_699_219, lcm_all(&steps));
+        assert_eq!(None, checked_lcm_all(&[u8::MAX, 2]));
+    }
 }
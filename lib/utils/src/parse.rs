@@ -0,0 +1,323 @@
+//! A small set of parser combinators for puzzle inputs.
+//!
+//! A parser is any function that takes the remaining input and returns the parsed value along with
+//! whatever input is left after it. Failures keep hold of the slice of input they failed at, so
+//! [`finish`] can turn them into a [`ParseError`] with the line and column of the problem.
+//!
+//! ```
+//! use utils::parse::*;
+//!
+//! let card = pair(
+//!     delimited(pair(tag("Card"), spaces()), integer::<u32>(), tag(": ")),
+//!     whitespace_separated(integer::<u32>()),
+//! );
+//!
+//! assert_eq!((1, vec![41, 48, 83]), finish("Card 1: 41 48 83", card).unwrap());
+//! ```
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// How much of the remaining input to show when describing what a parser found.
+const SNIPPET_LEN: usize = 16;
+
+/// The parsed value and the input left after it, or why parsing failed.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// A parser failure, holding the input it failed at and a description of what it expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub found: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(found: &'a str, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            found,
+            expected: expected.into(),
+        }
+    }
+
+    /// Converts the failure into an error positioned within `source`, the full text being parsed.
+    pub fn into_error(self, source: &str) -> ParseError {
+        let line_end = self.found.find('\n').unwrap_or(self.found.len());
+        let snippet = match self.found[..line_end].char_indices().nth(SNIPPET_LEN) {
+            Some((idx, _)) => &self.found[..idx],
+            None => &self.found[..line_end],
+        };
+
+        ParseError::at(source, snippet, self.expected)
+    }
+}
+
+/// Anything that can be used as a parser.
+pub trait Parser<'a, T>: Fn(&'a str) -> PResult<'a, T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&'a str) -> PResult<'a, T> {}
+
+/// Runs a parser over the whole input, allowing only trailing whitespace to be left over.
+pub fn finish<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let (value, rest) = parser(input).map_err(|f| f.into_error(input))?;
+    let rest = rest.trim_start();
+
+    if !rest.is_empty() {
+        return Err(Failure::new(rest, "end of input").into_error(input));
+    }
+
+    Ok(value)
+}
+
+/// Matches the literal text exactly.
+pub fn tag<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => Err(Failure::new(input, format!("'{literal}'"))),
+    }
+}
+
+/// Matches whichever of the literals comes first in the list.
+pub fn one_of<'a>(literals: &'static [&'static str]) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        literals
+            .iter()
+            .find_map(|literal| tag(literal)(input).ok())
+            .ok_or_else(|| {
+                let quoted = literals
+                    .iter()
+                    .map(|l| format!("'{l}'"))
+                    .collect::<Vec<String>>();
+
+                let expected = match quoted.split_last() {
+                    Some((last, rest)) if !rest.is_empty() => {
+                        format!("{} or {last}", rest.join(", "))
+                    }
+                    _ => quoted.join(""),
+                };
+
+                Failure::new(input, expected)
+            })
+    }
+}
+
+/// Matches a single line break, either `\n` or `\r\n`.
+pub fn newline<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = match input.as_bytes() {
+            [b'\r', b'\n', ..] => 2,
+            [b'\n', ..] => 1,
+            _ => return Err(Failure::new(input, "a new line")),
+        };
+
+        Ok((&input[..len], &input[len..]))
+    }
+}
+
+/// Matches zero or more spaces or tabs. This never fails.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| c != ' ' && c != '\t').unwrap_or(input.len());
+        Ok((&input[..end], &input[end..]))
+    }
+}
+
+/// Matches one or more characters that satisfy the predicate.
+pub fn take_while1<'a>(
+    expected: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+
+        if end == 0 {
+            return Err(Failure::new(input, expected));
+        }
+
+        Ok((&input[..end], &input[end..]))
+    }
+}
+
+/// Matches an optionally negative decimal integer. The integer must not run straight into a
+/// letter or another digit, so `12ab` is rejected rather than parsed as `12`.
+pub fn integer<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign_len = if input.starts_with('-') { 1 } else { 0 };
+        let end = input[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(input.len(), |idx| idx + sign_len);
+
+        let (num, rest) = input.split_at(end);
+        let runs_on = rest.starts_with(|c: char| c.is_alphanumeric() || c == '_');
+
+        match num.parse::<T>() {
+            Ok(value) if !runs_on => Ok((value, rest)),
+            _ => Err(Failure::new(input, "an integer")),
+        }
+    }
+}
+
+/// Transforms the value produced by a parser.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Fails, pointing at the start of the parsed value, unless the value satisfies the predicate.
+pub fn verify<'a, T>(
+    parser: impl Parser<'a, T>,
+    expected: &'static str,
+    predicate: impl Fn(&T) -> bool,
+) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+
+        if !predicate(&value) {
+            return Err(Failure::new(input, expected));
+        }
+
+        Ok((value, rest))
+    }
+}
+
+/// Matches both parsers in turn, keeping both values.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Matches both parsers, keeping only the second value.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// Matches both parsers, keeping only the first value.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// Matches all three parsers, keeping only the middle value.
+pub fn delimited<'a, A, B, C>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, B>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, B> {
+    preceded(open, terminated(parser, close))
+}
+
+/// Matches two values with a separator between them, keeping both values.
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+/// Matches one or more items with a separator between each. The list ends at the first separator
+/// that isn't followed by an item, leaving that separator unconsumed. If an item after a separator
+/// matches partway before failing, that failure is returned instead, as it's the more useful error.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator(rest) {
+            match item(after_separator) {
+                Ok((next, after)) => {
+                    items.push(next);
+                    rest = after;
+                }
+                Err(failure) if failure.found.len() < after_separator.len() => return Err(failure),
+                Err(_) => break,
+            }
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// Matches one or more items on the same line, separated by spaces.
+pub fn whitespace_separated<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, take_while1("a space", |c| c == ' ' || c == '\t'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn primitives() {
+        assert_eq!(Ok(("ab", "c")), tag("ab")("abc"));
+        assert_eq!(Ok(("red", ", 1")), one_of(&["blue", "red"])("red, 1"));
+        assert_eq!(Ok((-12, " x")), integer::<i32>()("-12 x"));
+        assert_eq!(Ok(("  ", "a")), spaces()("  a"));
+
+        assert!(integer::<u32>()("-12").is_err());
+        assert!(integer::<u32>()("12ab").is_err());
+        assert!(integer::<u32>()("").is_err());
+        assert!(take_while1("letters", char::is_alphabetic)("12").is_err());
+    }
+
+    #[test]
+    pub fn lists() {
+        let nums = whitespace_separated(integer::<u32>());
+        assert_eq!(Ok((vec![1, 2, 3], " | 4")), nums("1  2 3 | 4"));
+
+        let lines = separated(integer::<u32>(), newline());
+        assert_eq!(Ok((vec![1, 2, 3], "\n")), lines("1\r\n2\n3\n"));
+    }
+
+    #[test]
+    pub fn errors() {
+        let parser = pair(
+            terminated(integer::<u32>(), tag(": ")),
+            separated(one_of(&["red", "green", "blue"]), tag(", ")),
+        );
+
+        let parsed = finish("1: red, blue", &parser).unwrap();
+        assert_eq!((1, vec!["red", "blue"]), parsed);
+
+        let error = finish("1 red", &parser).unwrap_err();
+        assert_eq!(
+            "line 1, column 2: expected ': ', found ' red'",
+            error.to_string()
+        );
+
+        let error = finish("1: red, bleu", &parser).unwrap_err();
+        assert_eq!(
+            "line 1, column 7: expected end of input, found ', bleu'",
+            error.to_string()
+        );
+
+        let balls = separated_pair(integer::<u32>(), tag(" "), one_of(&["red", "blue"]));
+        let error = finish("3 red, 4 rde", separated(balls, tag(", "))).unwrap_err();
+        assert_eq!(
+            "line 1, column 10: expected 'red' or 'blue', found 'rde'",
+            error.to_string()
+        );
+
+        let error = finish("1: red\n2: pink", &parser).unwrap_err();
+        assert_eq!((Some(2), Some(1)), (error.line, error.column));
+
+        let error = finish("1: yellow", &parser).unwrap_err();
+        assert_eq!(
+            "line 1, column 4: expected 'red', 'green' or 'blue', found 'yellow'",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Coords;
+
+/// A position on an unbounded plane, which doubles as the offset between two positions. As with
+/// [`Coords`], `y` increases downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A [`Point`] used as an offset rather than a position.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Rotates a quarter turn clockwise about the origin, so up becomes right.
+    pub fn rotate_cw(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise about the origin, so up becomes left.
+    pub fn rotate_ccw(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl From<Coords> for Point {
+    fn from(coords: Coords) -> Self {
+        Point::new(coords.x as i64, coords.y as i64)
+    }
+}
+
+impl TryFrom<Point> for Coords {
+    type Error = TryFromIntError;
+
+    /// Fails if either component is negative.
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok(Coords {
+            x: usize::try_from(point.x)?,
+            y: usize::try_from(point.y)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Point;
+    use crate::grid::Coords;
+
+    #[test]
+    pub fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+
+        assert_eq!(Point::new(2, 3), a + b);
+        assert_eq!(Point::new(4, -7), a - b);
+        assert_eq!(Point::new(-3, 2), -a);
+        assert_eq!(Point::new(9, -6), a * 3);
+        assert_eq!(11, a.manhattan(b));
+        assert_eq!(0, a.manhattan(a));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    pub fn rotation() {
+        let up = Point::new(0, -1);
+        let right = Point::new(1, 0);
+
+        assert_eq!(right, up.rotate_cw());
+        assert_eq!(-right, up.rotate_ccw());
+
+        let p = Point::new(4, -7);
+        assert_eq!(p, p.rotate_cw().rotate_ccw());
+        assert_eq!(-p, p.rotate_cw().rotate_cw());
+        assert_eq!(p, p.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+    }
+
+    #[test]
+    pub fn coords() {
+        let coords = Coords { x: 4, y: 2 };
+        assert_eq!(Point::new(4, 2), Point::from(coords));
+        assert_eq!(Ok(coords), Coords::try_from(Point::new(4, 2)));
+        assert!(Coords::try_from(Point::new(-1, 2)).is_err());
+        assert!(Coords::try_from(Point::new(1, -2)).is_err());
+    }
+}
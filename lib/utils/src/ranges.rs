@@ -0,0 +1,325 @@
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as half-open ranges. The ranges are kept normalised: sorted, non-empty
+/// and with no two ranges overlapping or touching.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> RangeSet<T> {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// The normalised ranges that make up the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // The ranges are sorted, so find the last range starting at or before the value.
+        let idx = self.ranges.partition_point(|r| r.start <= value);
+        idx > 0 && value < self.ranges[idx - 1].end
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // Whichever range ends first can't overlap anything else in the other set.
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // Both inputs are normalised, so their overlaps already are too.
+        RangeSet { ranges }
+    }
+
+    /// The values in this set that are not in the other.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            // Skip ranges in the other set that end before this one starts.
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let removed = &other.ranges[k];
+
+                if removed.start > start {
+                    ranges.push(start..removed.start);
+                }
+
+                start = start.max(removed.end);
+                k += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Splits the set into the values below `at`, and the values at or above it.
+    pub fn split_at(&self, at: T) -> (RangeSet<T>, RangeSet<T>) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+
+        (RangeSet { ranges: below }, RangeSet { ranges: above })
+    }
+
+    fn normalise(&mut self) {
+        self.ranges.retain(|r| !r.is_empty());
+        self.ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        self.ranges = merged;
+    }
+}
+
+impl<T: Copy + Ord> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = RangeSet {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+/// A piecewise mapping that shifts each source range so that it starts at a destination value.
+/// Values outside every source range map to themselves. If source ranges overlap, the one
+/// inserted first takes precedence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    mappings: Vec<(Range<T>, T)>,
+}
+
+impl<T> RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> RangeMap<T> {
+        RangeMap {
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Maps the values in `source` onto the range of the same length starting at `dest`.
+    pub fn insert(&mut self, source: Range<T>, dest: T) {
+        self.mappings.push((source, dest));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        self.mappings
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map(|(source, dest)| *dest + (value - source.start))
+            .unwrap_or(value)
+    }
+
+    /// Maps every value in the set, splitting ranges wherever they cross a mapping boundary.
+    pub fn map_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = Vec::new();
+
+        for (source, dest) in &self.mappings {
+            let source_set = RangeSet::from(source.clone());
+
+            for range in unmapped.intersection(&source_set).ranges() {
+                let shift = |value: T| *dest + (value - source.start);
+                mapped.push(shift(range.start)..shift(range.end));
+            }
+
+            unmapped = unmapped.difference(&source_set);
+        }
+
+        mapped.extend(unmapped.ranges);
+        mapped.into_iter().collect()
+    }
+}
+
+impl<T> Default for RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    fn default() -> Self {
+        RangeMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use super::{RangeMap, RangeSet};
+    use crate::testing::Rng;
+
+    /// Values in the tests are kept below this, so that sets can be modelled as a bitset.
+    const DOMAIN: u64 = 64;
+    const CASES: usize = 1000;
+
+    fn range(rng: &mut Rng) -> Range<u64> {
+        let start = rng.below(DOMAIN);
+        let end = start + rng.below(DOMAIN - start + 1);
+        start..end
+    }
+
+    fn ranges(rng: &mut Rng) -> Vec<Range<u64>> {
+        let count = rng.below(6);
+        (0..count).map(|_| range(rng)).collect()
+    }
+
+    fn bitset(ranges: &[Range<u64>]) -> u128 {
+        ranges
+            .iter()
+            .flat_map(|r| r.clone())
+            .fold(0, |bits, v| bits | 1 << v)
+    }
+
+    fn assert_normalised(set: &RangeSet<u64>) {
+        for range in set.ranges() {
+            assert!(!range.is_empty(), "Empty range in {set:?}");
+        }
+
+        for pair in set.ranges().windows(2) {
+            assert!(pair[0].end < pair[1].start, "Unmerged ranges in {set:?}");
+        }
+    }
+
+    #[test]
+    pub fn from_ranges() {
+        let set: RangeSet<u64> = [5..8, 0..2, 2..3, 7..10, 12..12].into_iter().collect();
+        assert_eq!(&[0..3, 5..10], set.ranges());
+    }
+
+    #[test]
+    pub fn set_operations() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..CASES {
+            let (a, b) = (ranges(&mut rng), ranges(&mut rng));
+            let (a_bits, b_bits) = (bitset(&a), bitset(&b));
+            let a: RangeSet<u64> = a.into_iter().collect();
+            let b: RangeSet<u64> = b.into_iter().collect();
+
+            let cases = [
+                (a.union(&b), a_bits | b_bits),
+                (a.intersection(&b), a_bits & b_bits),
+                (a.difference(&b), a_bits & !b_bits),
+            ];
+
+            for (result, expected) in cases {
+                assert_normalised(&result);
+                assert_eq!(expected, bitset(result.ranges()), "a = {a:?}, b = {b:?}");
+            }
+
+            for v in 0..DOMAIN {
+                assert_eq!(a_bits & 1 << v != 0, a.contains(v));
+            }
+
+            let at = rng.below(DOMAIN);
+            let (below, above) = a.split_at(at);
+            let below_mask = (1u128 << at) - 1;
+            assert_normalised(&below);
+            assert_normalised(&above);
+            assert_eq!(a_bits & below_mask, bitset(below.ranges()));
+            assert_eq!(a_bits & !below_mask, bitset(above.ranges()));
+        }
+    }
+
+    #[test]
+    pub fn map_set() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..CASES {
+            let mut map = RangeMap::new();
+            for _ in 0..rng.below(4) {
+                map.insert(range(&mut rng), rng.below(DOMAIN));
+            }
+
+            let ranges = ranges(&mut rng);
+            let expected = ranges
+                .iter()
+                .flat_map(|r| r.clone())
+                .fold(0u128, |bits, v| bits | 1 << map.map(v));
+
+            let result = map.map_set(&ranges.into_iter().collect());
+            assert_normalised(&result);
+            assert_eq!(expected, bitset(result.ranges()), "map = {map:?}");
+        }
+    }
+}
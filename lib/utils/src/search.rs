@@ -0,0 +1,387 @@
+//! Generic graph searches, driven by a closure giving the neighbours of each node. Nodes can be
+//! anything hashable, such as grid [`Coords`](crate::grid::Coords) or string ids.
+//!
+//! Each search starts from a single node and runs until it settles a node that satisfies
+//! `is_goal`, or until it runs out of nodes. Pass `|_| false` to explore everything reachable.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The result of a search: the cost of reaching each node the search settled, and the route it
+/// took to get there.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn new(start: N) -> Search<N, C> {
+        Search {
+            start,
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The node that satisfied the goal, if one was reached.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of reaching the goal, if one was reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The cost of reaching a node, or `None` if the search never reached it.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    /// The route to the goal, from the start to the goal inclusive.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    /// The route to a node, from the start to the node inclusive.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        let mut current = node;
+
+        while *current != self.start {
+            current = &self.parents[current];
+            path.push(current.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, where every step costs 1. The cost of each node is the length of the
+/// shortest path to it.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    search.costs.insert(start.clone(), 0);
+
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let cost = search.costs[&node];
+
+        for next in neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost + 1);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Depth-first search, where every step costs 1. The cost of each node is the length of the path
+/// the search took to it, which isn't necessarily the shortest.
+pub fn dfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut stack = vec![(start, None)];
+
+    while let Some((node, parent)) = stack.pop() {
+        if search.costs.contains_key(&node) {
+            continue;
+        }
+
+        let cost = parent.as_ref().map_or(0, |p| search.costs[p] + 1);
+        search.costs.insert(node.clone(), cost);
+
+        if let Some(parent) = parent {
+            search.parents.insert(node.clone(), parent);
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for next in neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                stack.push((next, Some(node.clone())));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, where `neighbours` gives each neighbour along with the non-negative cost
+/// of stepping to it. The cost of each node is the cost of the cheapest path to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which is Dijkstra's algorithm guided towards the goal by a heuristic. The heuristic
+/// estimates the remaining cost from a node to the goal, and must never overestimate it, nor drop
+/// by more than the cost of any single step.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone());
+
+    // The cheapest known cost to each node that hasn't been settled yet, and where it came from.
+    let mut frontier: HashMap<N, (C, Option<N>)> = HashMap::new();
+    frontier.insert(start.clone(), (C::default(), None));
+
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        node: start,
+    }]);
+
+    while let Some(Entry { node, .. }) = queue.pop() {
+        // Nodes can be queued several times, but only the cheapest is settled.
+        let Some((cost, parent)) = frontier.remove(&node) else {
+            continue;
+        };
+
+        search.costs.insert(node.clone(), cost);
+
+        if let Some(parent) = parent {
+            search.parents.insert(node.clone(), parent);
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            if search.costs.contains_key(&next) {
+                continue;
+            }
+
+            let next_cost = cost + step;
+            let cheaper = match frontier.get(&next) {
+                Some(&(known, _)) => next_cost < known,
+                None => true,
+            };
+
+            if cheaper {
+                frontier.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    node: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// A node waiting in A*'s priority queue, ordered so the lowest priority is popped first.
+struct Entry<N, C> {
+    priority: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use super::{astar, bfs, dfs, dijkstra};
+    use crate::{
+        grid::{Coords, Grid},
+        point::Point,
+    };
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E";
+
+    fn maze() -> (Grid<char>, Coords, Coords) {
+        let grid = Grid::<char>::from_str(MAZE).unwrap();
+        let find = |target| grid.coords().find(|&c| grid[c] == target).unwrap();
+        let (start, end) = (find('S'), find('E'));
+        (grid, start, end)
+    }
+
+    /// A weighted graph between string ids, with the cheapest route from a to e being a-c-b-d-e.
+    fn weighted() -> HashMap<&'static str, Vec<(&'static str, u32)>> {
+        HashMap::from([
+            ("a", vec![("b", 7), ("c", 2)]),
+            ("b", vec![("d", 1)]),
+            ("c", vec![("b", 3), ("d", 8)]),
+            ("d", vec![("e", 2)]),
+            ("e", vec![]),
+            ("f", vec![("a", 1)]),
+        ])
+    }
+
+    fn assert_connected(grid: &Grid<char>, path: &[Coords]) {
+        for pair in path.windows(2) {
+            assert_eq!(1, Point::from(pair[0]).manhattan(Point::from(pair[1])));
+            assert_ne!('#', grid[pair[1]]);
+        }
+    }
+
+    #[test]
+    pub fn breadth_first() {
+        let (grid, start, end) = maze();
+        let open = |c: &Coords| grid.neighbours4(*c).filter(|&n| grid[n] != '#');
+
+        let search = bfs(start, open, |&c| c == end);
+        assert_eq!(Some(&end), search.goal());
+        assert_eq!(Some(12), search.goal_cost());
+
+        let path = search.path().unwrap();
+        assert_eq!(13, path.len());
+        assert_eq!((start, end), (path[0], path[12]));
+        assert_connected(&grid, &path);
+
+        let all = bfs(start, open, |_| false);
+        assert_eq!(None, all.goal());
+        assert_eq!(Some(2), all.cost(&Coords { x: 0, y: 2 }));
+        assert_eq!(None, all.cost(&Coords { x: 2, y: 0 }));
+        assert_eq!(
+            grid.iter().filter(|&&c| c != '#').count(),
+            all.costs().len()
+        );
+        assert_eq!(Some(vec![start]), all.path_to(&start));
+    }
+
+    #[test]
+    pub fn depth_first() {
+        let (grid, start, end) = maze();
+        let open = |c: &Coords| grid.neighbours4(*c).filter(|&n| grid[n] != '#');
+
+        let search = dfs(start, open, |&c| c == end);
+        let path = search.path().unwrap();
+        assert_eq!((start, end), (path[0], *path.last().unwrap()));
+        assert_eq!(Some(path.len() - 1), search.goal_cost());
+        assert_connected(&grid, &path);
+
+        let graph = weighted();
+        let reachable = dfs("a", |n| graph[n].iter().map(|&(m, _)| m), |_| false);
+        let mut found = reachable.costs().keys().copied().collect::<Vec<_>>();
+        found.sort();
+        assert_eq!(vec!["a", "b", "c", "d", "e"], found);
+    }
+
+    #[test]
+    pub fn weighted_searches() {
+        let graph = weighted();
+        let edges = |n: &&str| graph[n].clone();
+
+        let search = dijkstra("a", edges, |&n| n == "e");
+        assert_eq!(Some(8), search.goal_cost());
+        assert_eq!(Some(vec!["a", "c", "b", "d", "e"]), search.path());
+
+        let all = dijkstra("a", edges, |_| false);
+        assert_eq!(Some(5), all.cost(&"b"));
+        assert_eq!(Some(6), all.cost(&"d"));
+        assert_eq!(None, all.cost(&"f"));
+        assert_eq!(None, all.path_to(&"f"));
+    }
+
+    #[test]
+    pub fn astar_matches_dijkstra() {
+        let (grid, start, end) = maze();
+
+        // Stepping onto a '.' costs 1, and onto anything else costs 5.
+        let edges = |c: &Coords| {
+            grid.neighbours4(*c)
+                .filter(|&n| grid[n] != '#')
+                .map(|n| (n, if grid[n] == '.' { 1 } else { 5 }))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |c: &Coords| Point::from(*c).manhattan(Point::from(end));
+
+        let expected = dijkstra(start, edges, |&c| c == end);
+        let search = astar(start, edges, heuristic, |&c| c == end);
+
+        assert_eq!(Some(16), expected.goal_cost());
+        assert_eq!(expected.goal_cost(), search.goal_cost());
+        assert_connected(&grid, &search.path().unwrap());
+        assert!(search.costs().len() <= expected.costs().len());
+    }
+}
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{self, Parser},
+    parse_lines_iter_with, LineOptions, ParseError,
+};
+
+/// A block of consecutive non-blank lines within a larger input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    source: &'a str,
+    text: &'a str,
+    line: usize,
+}
+
+impl<'a> Section<'a> {
+    /// The text of the section, without any trailing line break or whitespace.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The 1-based line of the full input that the section starts on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The lines of the section, without line endings or trailing whitespace.
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.split('\n').map(str::trim_end)
+    }
+
+    /// Splits off the first line of the section, such as a header, from the lines after it.
+    pub fn split_first(&self) -> (Section<'a>, Section<'a>) {
+        let (first, rest) = self.text.split_once('\n').unwrap_or((self.text, ""));
+
+        let first = Section {
+            text: first.trim_end(),
+            ..*self
+        };
+        let rest = Section {
+            text: rest,
+            line: self.line + 1,
+            ..*self
+        };
+
+        (first, rest)
+    }
+
+    /// Parses each line of the section, positioning any error within the full input.
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        self.parse_lines_with(LineOptions::default())
+    }
+
+    /// Parses each line of the section as configured by the options, positioning any error within
+    /// the full input.
+    pub fn parse_lines_with<T>(&self, options: LineOptions) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        parse_lines_iter_with(self.lines(), options).map_err(|e| e.offset(self.line))
+    }
+
+    /// Parses the whole section, positioning any error within the full input.
+    pub fn parse<T>(&self, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+        parse::finish(self.text, parser).map_err(|e| e.within(self.source, self.text))
+    }
+}
+
+/// Splits the data into sections separated by one or more blank lines. Lines holding only
+/// whitespace count as blank, and both `\n` and `\r\n` line endings are accepted.
+pub fn sections(data: &str) -> impl Iterator<Item = Section<'_>> {
+    let mut lines = data.split_inclusive('\n').enumerate();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        let mut start = None;
+
+        for (idx, line) in lines.by_ref() {
+            let line_start = offset;
+            offset += line.len();
+
+            match (start, line.trim().is_empty()) {
+                (None, false) => start = Some((idx + 1, line_start)),
+                (Some((line, from)), true) => {
+                    return Some(Section {
+                        source: data,
+                        text: data[from..line_start].trim_end(),
+                        line,
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        start.map(|(line, from)| Section {
+            source: data,
+            text: data[from..].trim_end(),
+            line,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sections;
+    use crate::{parse, testing::Num, BlankLines, LineOptions};
+
+    #[test]
+    pub fn split_sections() {
+        let data = "\r\nseeds: 1 2\r\n\r\na\r\nb  \r\n \t\r\n\r\nc\n";
+        let found = sections(data)
+            .map(|s| (s.line(), s.lines().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![(2, vec!["seeds: 1 2"]), (4, vec!["a", "b"]), (8, vec!["c"])],
+            found
+        );
+
+        assert_eq!(0, sections("").count());
+        assert_eq!(0, sections("\n  \n").count());
+    }
+
+    #[test]
+    pub fn section_errors() {
+        let data = "header\n\nmaps:\n1\nx\n";
+        let (header, rest) = sections(data).nth(1).unwrap().split_first();
+        assert_eq!("maps:", header.text());
+
+        let nums = sections("1\r\n2 \r\n").next().unwrap().parse_lines::<Num>();
+        assert_eq!(
+            vec![1, 2],
+            nums.unwrap().iter().map(|n| n.0).collect::<Vec<_>>()
+        );
+
+        let error = rest.parse_lines::<Num>().unwrap_err();
+        assert_eq!(Some(5), error.line);
+
+        let error = rest.parse(parse::tag("2")).unwrap_err();
+        assert_eq!((Some(4), Some(1)), (error.line, error.column));
+    }
+
+    #[test]
+    pub fn section_line_options() {
+        let data = "maps:
+ 1
+ 2x
+
+empty:
+";
+        let trim = LineOptions {
+            trim: true,
+            ..LineOptions::default()
+        };
+
+        let (_, body) = sections(data).next().unwrap().split_first();
+        let error = body.parse_lines_with::<Num>(trim).unwrap_err();
+        assert_eq!((Some(3), Some(2)), (error.line, error.column));
+
+        // A header on its own leaves a single blank line behind it.
+        let (_, empty) = sections(data).nth(1).unwrap().split_first();
+        assert!(empty.parse_lines_with::<Num>(trim).unwrap().is_empty());
+
+        let reject = LineOptions {
+            blank_lines: BlankLines::Reject,
+            ..trim
+        };
+        let error = empty.parse_lines_with::<Num>(reject).unwrap_err();
+        assert_eq!(Some(6), error.line);
+    }
+}
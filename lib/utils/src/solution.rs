@@ -0,0 +1,180 @@
+use std::{
+    fmt::Display,
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{input::InputSource, ParseError};
+
+/// A solution to a single day's puzzle, split into parsing the input and then solving each part
+/// against the parsed input.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    /// The day of the puzzle this solves.
+    const DAY: u8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// The answers produced by a run, with `None` for any part that wasn't run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// How long each stage of a run took, with `None` for any part that wasn't run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+/// An object safe view of a [`Solution`], so that solutions with different input and answer
+/// types can be run side by side.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    /// Parses the input and runs the given part, or both parts if none is given, timing each stage.
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Report, ParseError>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Report, ParseError> {
+        let (input, parse) = timed(|| S::parse(input));
+        let input = input?;
+
+        let mut report = Report {
+            timings: Timings {
+                parse,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        if matches!(part, None | Some(Part::One)) {
+            let (answer, elapsed) = timed(|| S::part1(&input));
+            report.answers.part1 = Some(answer.to_string());
+            report.timings.part1 = Some(elapsed);
+        }
+
+        if matches!(part, None | Some(Part::Two)) {
+            let (answer, elapsed) = timed(|| S::part2(&input));
+            report.answers.part2 = Some(answer.to_string());
+            report.timings.part2 = Some(elapsed);
+        }
+
+        Ok(report)
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs both parts of a solution and prints the results. The input is read from the path given as
+/// the first command line argument, from stdin if that argument is `-`, or otherwise from the
+/// day's conventional input file.
+pub fn run<S: Solution>() {
+    let arg = std::env::args().nth(1);
+    let input = InputSource::from_arg(arg.as_deref(), S::DAY)
+        .load()
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
+
+    let input = S::parse(&input).unwrap_or_else(|e| {
+        eprintln!("Failed to parse input: {e}");
+        process::exit(1);
+    });
+
+    println!("Part 1 result: {}", S::part1(&input));
+    println!("Part 2 result: {}", S::part2(&input));
+}
+
+/// Generates a test for each part of a solution, checking it against an example input and the
+/// answer given for it in the puzzle description.
+///
+/// ```ignore
+/// utils::example_tests! {
+///     Day07,
+///     input: include_str!("../test-input.txt"),
+///     part1: 6440,
+///     part2: 5905,
+/// }
+/// ```
+///
+/// If the parts use different examples, each part can be given its own input instead:
+///
+/// ```ignore
+/// utils::example_tests! {
+///     Day01,
+///     part1: (include_str!("../test-input.txt"), 142),
+///     part2: (include_str!("../test-input-2.txt"), 281),
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, input: $input:expr, part1: $part1:expr, part2: $part2:expr $(,)?) => {
+        $crate::example_tests! {
+            $solution,
+            part1: ($input, $part1),
+            part2: ($input, $part2),
+        }
+    };
+    (
+        $solution:ty,
+        part1: ($input1:expr, $part1:expr),
+        part2: ($input2:expr, $part2:expr) $(,)?
+    ) => {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+            use $crate::solution::Solution;
+
+            #[test]
+            fn part1() {
+                let input = <$solution>::parse($input1).expect("Failed to parse example input");
+                assert_eq!($part1, <$solution>::part1(&input));
+            }
+
+            #[test]
+            fn part2() {
+                let input = <$solution>::parse($input2).expect("Failed to parse example input");
+                assert_eq!($part2, <$solution>::part2(&input));
+            }
+        }
+    };
+}
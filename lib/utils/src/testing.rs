@@ -0,0 +1,34 @@
+//! Helpers shared by the unit tests.
+
+use std::str::FromStr;
+
+use crate::{parse, ParseError};
+
+/// A small xorshift generator, so the property tests are reproducible.
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A value in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// A number that parses from a line, for testing the line and section helpers.
+#[derive(Debug)]
+pub struct Num(pub u32);
+
+impl FromStr for Num {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::finish(s, parse::map(parse::integer(), Num))
+    }
+}